@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use crate::alphabet::Alphabet;
+use crate::modarith::{inverse, solve_congruence};
+use crate::text::coincidence;
+
+pub const ALPHABET: &str = "абвгдежзийклмнопрстуфхцчшщьыэюя";
+pub const MOST_FREQUENT_BIGRAMS: [&str; 5] = ["ст", "но", "то", "на", "ен"];
+
+pub type AffineKey = (u64, u64);
+
+pub fn alphabet() -> Alphabet {
+    Alphabet::new(ALPHABET).unwrap()
+}
+
+pub fn modulus(alphabet: &Alphabet) -> u64 {
+    (alphabet.size() * alphabet.size()) as u64
+}
+
+pub fn preprocess(text: &str, alphabet: &Alphabet) -> String {
+    text.chars()
+        .map(|c| c.to_lowercase().next().unwrap_or(c))
+        .map(|c| if c == 'ё' { 'е' } else { c })
+        .filter(|&c| alphabet.contains(c))
+        .collect()
+}
+
+pub fn bigram_value(first: char, second: char, alphabet: &Alphabet) -> Option<u64> {
+    Some((alphabet.index_of(first)? * alphabet.size() + alphabet.index_of(second)?) as u64)
+}
+
+pub fn value_bigram(value: u64, alphabet: &Alphabet) -> [char; 2] {
+    let m = alphabet.size() as u64;
+    [alphabet.char_at((value / m) as usize), alphabet.char_at((value % m) as usize)]
+}
+
+fn bigram_values(text: &str, alphabet: &Alphabet) -> Vec<u64> {
+    let letters: Vec<char> = text.chars().filter(|&c| alphabet.contains(c)).collect();
+    letters
+        .chunks_exact(2)
+        .map(|pair| bigram_value(pair[0], pair[1], alphabet).unwrap())
+        .collect()
+}
+
+pub fn encode(text: &str, key: AffineKey, alphabet: &Alphabet) -> String {
+    let modulus = modulus(alphabet);
+    let (a, b) = key;
+
+    bigram_values(text, alphabet)
+        .into_iter()
+        .flat_map(|x| value_bigram((a * x + b) % modulus, alphabet))
+        .collect()
+}
+
+pub fn decode(text: &str, key: AffineKey, alphabet: &Alphabet) -> Option<String> {
+    let modulus = modulus(alphabet);
+    let (a, b) = key;
+    let inverse_a = inverse(a as i64, modulus as i64)? as u64;
+
+    Some(
+        bigram_values(text, alphabet)
+            .into_iter()
+            .flat_map(|y| value_bigram(inverse_a * ((y + modulus - b % modulus) % modulus) % modulus, alphabet))
+            .collect(),
+    )
+}
+
+fn ranked_by_count<T: Clone + Eq + std::hash::Hash>(items: impl Iterator<Item = T>) -> Vec<(T, usize)> {
+    let mut order: Vec<T> = Vec::new();
+    let mut counts: HashMap<T, usize> = HashMap::new();
+
+    for item in items {
+        let count = counts.entry(item.clone()).or_insert(0);
+        if *count == 0 {
+            order.push(item);
+        }
+        *count += 1;
+    }
+
+    let mut ranked: Vec<(T, usize)> = order.into_iter().map(|item| { let count = counts[&item]; (item, count) }).collect();
+    ranked.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+    ranked
+}
+
+pub fn bigram_frequencies(text: &str, alphabet: &Alphabet) -> Vec<(String, usize)> {
+    ranked_by_count(bigram_values(text, alphabet).into_iter())
+        .into_iter()
+        .map(|(value, count)| (value_bigram(value, alphabet).iter().collect(), count))
+        .collect()
+}
+
+pub fn top_bigrams(text: &str, n: usize, alphabet: &Alphabet) -> Vec<String> {
+    bigram_frequencies(text, alphabet).into_iter().take(n).map(|(bigram, _)| bigram).collect()
+}
+
+pub fn candidate_keys(cipher_bigrams: &[String], language_bigrams: &[&str], alphabet: &Alphabet) -> Vec<AffineKey> {
+    let modulus = modulus(alphabet) as i64;
+    let value = |bigram: &str| {
+        let chars: Vec<char> = bigram.chars().collect();
+        bigram_value(chars[0], chars[1], alphabet).unwrap() as i64
+    };
+
+    let mut keys: Vec<AffineKey> = Vec::new();
+    for (i, &x1) in language_bigrams.iter().enumerate() {
+        for (j, &x2) in language_bigrams.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+
+            for (k, y1) in cipher_bigrams.iter().enumerate() {
+                for (l, y2) in cipher_bigrams.iter().enumerate() {
+                    if k == l {
+                        continue;
+                    }
+
+                    let (x1, x2, y1, y2) = (value(x1), value(x2), value(y1), value(y2));
+                    for a in solve_congruence(x1 - x2, y1 - y2, modulus) {
+                        let key = (a as u64, (y1 - a * x1).rem_euclid(modulus) as u64);
+                        if !keys.contains(&key) {
+                            keys.push(key);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    keys
+}
+
+pub fn text_rate(text: &str, alphabet: &Alphabet, expected_i: f64) -> f64 {
+    let frequencies = ranked_by_count(text.chars());
+    let rank_size = 4.min(frequencies.len());
+    let mut score = 0.0;
+
+    let top: Vec<char> = frequencies.iter().take(rank_size).map(|&(c, _)| c).collect();
+    if ['о', 'е', 'а'].iter().any(|c| top.contains(c)) {
+        score += 1.0;
+    }
+
+    let bottom: Vec<char> = frequencies.iter().rev().take(rank_size).map(|&(c, _)| c).collect();
+    if ['ф', 'ц', 'щ'].iter().any(|c| bottom.contains(c)) {
+        score += 1.0;
+    }
+
+    score - (coincidence(text, alphabet) - expected_i).abs() * 200.0
+}
+
+pub fn crack(text: &str, language_bigrams: &[&str], alphabet: &Alphabet, expected_i: f64) -> Vec<(AffineKey, f64)> {
+    let cipher_bigrams = top_bigrams(text, language_bigrams.len(), alphabet);
+
+    let mut rated: Vec<(AffineKey, f64)> = candidate_keys(&cipher_bigrams, language_bigrams, alphabet)
+        .into_iter()
+        .filter_map(|key| decode(text, key, alphabet).map(|plaintext| (key, text_rate(&plaintext, alphabet, expected_i))))
+        .collect();
+
+    rated.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    rated
+}
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+pub const RUSSIAN_32: &str = "абвгдежзийклмнопрстуфхцчшщъыьэюя";
+pub const RUSSIAN_33: &str = "абвгдеёжзийклмнопрстуфхцчшщъыьэюя";
+pub const UKRAINIAN_33: &str = "абвгґдеєжзиіїйклмнопрстуфхцчшщьюя";
+pub const ENGLISH_26: &str = "abcdefghijklmnopqrstuvwxyz";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alphabet {
+    letters: Vec<char>,
+    indices: HashMap<char, usize>,
+}
+
+impl Alphabet {
+    pub fn new(letters: &str) -> Option<Alphabet> {
+        let letters: Vec<char> = letters.chars().collect();
+        let mut indices = HashMap::with_capacity(letters.len());
+
+        for (index, &c) in letters.iter().enumerate() {
+            if indices.insert(c, index).is_some() {
+                return None;
+            }
+        }
+
+        if letters.is_empty() {
+            None
+        } else {
+            Some(Alphabet { letters, indices })
+        }
+    }
+
+    pub fn russian_32() -> Alphabet {
+        Alphabet::new(RUSSIAN_32).unwrap()
+    }
+
+    pub fn russian_33() -> Alphabet {
+        Alphabet::new(RUSSIAN_33).unwrap()
+    }
+
+    pub fn ukrainian_33() -> Alphabet {
+        Alphabet::new(UKRAINIAN_33).unwrap()
+    }
+
+    pub fn english_26() -> Alphabet {
+        Alphabet::new(ENGLISH_26).unwrap()
+    }
+
+    pub fn from_name(name: &str) -> Option<Alphabet> {
+        match name {
+            "russian-32" | "ru32" => Some(Alphabet::russian_32()),
+            "russian-33" | "ru33" => Some(Alphabet::russian_33()),
+            "ukrainian-33" | "uk33" => Some(Alphabet::ukrainian_33()),
+            "english-26" | "en26" => Some(Alphabet::english_26()),
+            _ => None,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.letters.len()
+    }
+
+    pub fn letters(&self) -> &[char] {
+        &self.letters
+    }
+
+    pub fn contains(&self, c: char) -> bool {
+        self.indices.contains_key(&c)
+    }
+
+    pub fn index_of(&self, c: char) -> Option<usize> {
+        self.indices.get(&c).copied()
+    }
+
+    pub fn char_at(&self, index: usize) -> char {
+        self.letters[index % self.letters.len()]
+    }
+
+    pub fn add(&self, a: char, b: char) -> Option<char> {
+        let a = self.index_of(a)?;
+        let b = self.index_of(b)?;
+        Some(self.char_at(a + b))
+    }
+
+    pub fn sub(&self, a: char, b: char) -> Option<char> {
+        let a = self.index_of(a)?;
+        let b = self.index_of(b)?;
+        Some(self.char_at(a + self.size() - b))
+    }
+}
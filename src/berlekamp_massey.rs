@@ -0,0 +1,83 @@
+use crate::polynomial::FeedbackPolynomial;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectionPolynomial {
+    pub coefficients: Vec<u8>,
+    pub linear_complexity: usize,
+}
+
+impl ConnectionPolynomial {
+    pub fn feedback_polynomial(&self) -> Option<FeedbackPolynomial> {
+        let exponents: Vec<usize> = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .filter(|&(_, &c)| c == 1)
+            .map(|(i, _)| self.linear_complexity - i)
+            .collect();
+        FeedbackPolynomial::from_exponents(&exponents)
+    }
+
+    pub fn taps(&self) -> Option<u32> {
+        if self.linear_complexity > 32 {
+            return None;
+        }
+
+        let mut taps = 0u32;
+        for (i, &c) in self.coefficients.iter().enumerate().skip(1) {
+            if c == 1 {
+                taps |= 1 << (self.linear_complexity - i);
+            }
+        }
+        Some(taps)
+    }
+}
+
+fn berlekamp_massey_with_profile(sequence: &[u8]) -> (ConnectionPolynomial, Vec<usize>) {
+    let mut c = vec![1u8];
+    let mut b = vec![1u8];
+    let mut l = 0usize;
+    let mut m: isize = -1;
+    let mut profile = Vec::with_capacity(sequence.len());
+
+    for n in 0..sequence.len() {
+        let mut discrepancy = sequence[n];
+        for i in 1..=l.min(c.len() - 1) {
+            discrepancy ^= c[i] & sequence[n - i];
+        }
+
+        if discrepancy == 1 {
+            let previous = c.clone();
+            let shift = (n as isize - m) as usize;
+            if c.len() < b.len() + shift {
+                c.resize(b.len() + shift, 0);
+            }
+            for (i, &bit) in b.iter().enumerate() {
+                c[i + shift] ^= bit;
+            }
+
+            if 2 * l <= n {
+                l = n + 1 - l;
+                m = n as isize;
+                b = previous;
+            }
+        }
+
+        profile.push(l);
+    }
+
+    c.resize(l + 1, 0);
+    (ConnectionPolynomial { coefficients: c, linear_complexity: l }, profile)
+}
+
+pub fn berlekamp_massey(sequence: &[u8]) -> ConnectionPolynomial {
+    berlekamp_massey_with_profile(sequence).0
+}
+
+pub fn linear_complexity(sequence: &[u8]) -> usize {
+    berlekamp_massey(sequence).linear_complexity
+}
+
+pub fn linear_complexity_profile(sequence: &[u8]) -> Vec<usize> {
+    berlekamp_massey_with_profile(sequence).1
+}
@@ -0,0 +1,62 @@
+use std::fs;
+use std::io::{self};
+use structopt::StructOpt;
+
+use symmetric_crypto::affine_bigram::{alphabet, crack, decode, encode, preprocess, top_bigrams, MOST_FREQUENT_BIGRAMS};
+use symmetric_crypto::profile::LanguageProfile;
+
+const INPUT_FILE: &str = "../../text_files/affine_bigram_analysis/input.txt";
+const OUTPUT_FILE: &str = "../../text_files/affine_bigram_analysis/output.txt";
+const PROFILE_FILE: &str = "../../text_files/profiles/russian_32.txt";
+
+#[derive(StructOpt)]
+struct Cli {
+    /// What to do with the input: crack, encode or decode
+    #[structopt(long, default_value = "crack")]
+    mode: String,
+    /// Key multiplier a (encode and decode modes)
+    #[structopt(short, default_value = "1")]
+    a: u64,
+    /// Key shift b (encode and decode modes)
+    #[structopt(short, default_value = "0")]
+    b: u64,
+    #[structopt(long, default_value = INPUT_FILE)]
+    input: String,
+    #[structopt(long, default_value = OUTPUT_FILE)]
+    output: String,
+    /// Language profile whose index of coincidence the recognizer expects
+    #[structopt(long, default_value = PROFILE_FILE)]
+    profile: String,
+}
+
+fn main() -> io::Result<()> {
+    let args = Cli::from_args();
+    let alphabet = alphabet();
+    let text = preprocess(&fs::read_to_string(&args.input)?, &alphabet);
+
+    let result = match args.mode.as_str() {
+        "encode" => encode(&text, (args.a, args.b), &alphabet),
+        "decode" => decode(&text, (args.a, args.b), &alphabet)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("a = {} is not invertible", args.a)))?,
+        "crack" => {
+            let expected_i = LanguageProfile::load(&args.profile)?.expected_coincidence();
+            println!("Best 5 bigrams: {:?}", top_bigrams(&text, 5, &alphabet));
+
+            let rated = crack(&text, &MOST_FREQUENT_BIGRAMS, &alphabet, expected_i);
+            println!("Top 5 rated:");
+            for (key, rate) in rated.iter().take(5) {
+                println!("{} : {:?}", rate, key);
+            }
+
+            let &(best_key, _) = rated
+                .first()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "no candidate key decrypts the text"))?;
+            decode(&text, best_key, &alphabet).unwrap()
+        }
+        mode => return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("unknown mode '{}'", mode))),
+    };
+
+    println!("{}", result);
+    fs::write(&args.output, &result)?;
+    Ok(())
+}
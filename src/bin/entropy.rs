@@ -0,0 +1,90 @@
+use std::io::{self};
+use structopt::StructOpt;
+
+use symmetric_crypto::alphabet::Alphabet;
+use symmetric_crypto::entropy::{compute_h1, compute_h2, count_bigram_probabilities, count_letters_probabilities, get_bigram_frequency};
+use symmetric_crypto::errors::invalid_data;
+use symmetric_crypto::profile::LanguageProfile;
+use symmetric_crypto::text::{process_file, print_letter_frequencies, print_letters_probabilities, print_bigram_frequencies, print_bigram_probabilities, get_letter_frequency};
+
+#[derive(StructOpt)]
+struct Cli {
+    /// Alphabet of the language profile to save or load
+    #[structopt(long, default_value = "russian-33")]
+    alphabet: String,
+    /// Build a language profile from the corpus and save it to this file
+    #[structopt(long)]
+    save_profile: Option<String>,
+    /// Include trigram frequencies in the saved profile
+    #[structopt(long)]
+    trigrams: bool,
+    /// Print entropies of an existing language profile instead of analyzing the corpus
+    #[structopt(long)]
+    profile: Option<String>,
+}
+
+fn analyze_file(input_file: &str, output_file: &str, with_spaces: bool, alphabet: &Alphabet) -> io::Result<String> {
+    let processed_text = process_file(input_file, output_file, with_spaces, alphabet)?;
+    let letter_frequencies = get_letter_frequency(&processed_text);
+    print_letter_frequencies(&letter_frequencies);
+    let letter_prob = count_letters_probabilities(&letter_frequencies);
+    print_letters_probabilities(&letter_prob);
+    let h1 = compute_h1(&letter_frequencies);
+    println!("h1: {}", h1);
+
+    let bigram_frequencies = get_bigram_frequency(&processed_text);
+    print_bigram_frequencies(&bigram_frequencies);
+    let bigram_prob = count_bigram_probabilities(&bigram_frequencies);
+    print_bigram_probabilities(&bigram_prob);
+    let h2 = compute_h2(&bigram_frequencies);
+    println!("h2: {}", h2);
+
+    println!("File analyzing completed. Processed text saved to {}", output_file);
+    Ok(processed_text)
+}
+
+fn analyze_profile(profile_file: &str, alphabet: &Alphabet) -> io::Result<()> {
+    let profile = LanguageProfile::load(profile_file)?;
+    profile.check_alphabet(alphabet)?;
+
+    for (letter, probability) in alphabet.letters().iter().zip(profile.unigrams()) {
+        println!("{}: {}", letter, probability);
+    }
+    println!();
+
+    println!("h1: {}", profile.h1());
+    println!("h2: {}", profile.h2());
+    Ok(())
+}
+
+fn main() -> io::Result<()> {
+    let args = Cli::from_args();
+    let alphabet = Alphabet::from_name(&args.alphabet)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("unknown alphabet '{}'", args.alphabet)))?;
+
+    if let Some(profile_file) = &args.profile {
+        return analyze_profile(profile_file, &alphabet);
+    }
+
+    let input_file = "../../text_files/entropy/boloto.txt";
+    let processed_file = "../../text_files/entropy/boloto_processed.txt";
+    let without_spaces_file = "../../text_files/entropy/boloto_without_spaces.txt";
+    let mut with_spaces = true;
+
+    let processed_text = analyze_file(input_file, processed_file, with_spaces, &alphabet);
+    
+    with_spaces = false;
+    let _ = analyze_file(processed_file, without_spaces_file, with_spaces, &alphabet);
+
+    if let Some(profile_file) = &args.save_profile {
+        let processed_text = processed_text?;
+        if !processed_text.chars().any(|c| alphabet.contains(c)) {
+            return Err(invalid_data(format!("{} has no letters of the alphabet '{}'", input_file, args.alphabet)));
+        }
+        let profile = LanguageProfile::from_corpus(&processed_text, &alphabet, args.trigrams);
+        profile.save(profile_file)?;
+        println!("Language profile saved to {}", profile_file);
+    }
+
+    Ok(())
+}
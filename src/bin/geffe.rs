@@ -0,0 +1,280 @@
+use std::time::Instant;
+use structopt::StructOpt;
+
+use symmetric_crypto::berlekamp_massey::{berlekamp_massey, linear_complexity_profile};
+use symmetric_crypto::bits::BitVector;
+use symmetric_crypto::bitstream::{read_bits, BitFormat, BitOrder};
+use symmetric_crypto::boolean::BooleanFunction;
+use symmetric_crypto::geffe_attack::{attack, parse_function, AttackConfig, AttackError, CandidatePair, MIN_CORRELATION_BIAS};
+use symmetric_crypto::lfsr::LFSR;
+use symmetric_crypto::polynomial::FeedbackPolynomial;
+
+fn report_progress(done: u64, total: u64) {
+    eprint!("\rsearching: {}/{} chunks", done, total);
+    if done == total {
+        eprintln!();
+    }
+}
+
+// Built-in variants live next to their keystreams in text_files/geffe
+fn preset_file(name: &str) -> Option<String> {
+    match name {
+        "beta" | "sigma" => Some(format!("../../text_files/geffe/{}.toml", name)),
+        _ => None,
+    }
+}
+
+fn print_combiner(function: &BooleanFunction) {
+    println!(
+        "Combining function: {} (nonlinearity {}, correlation immunity {})",
+        function,
+        function.nonlinearity(),
+        function.correlation_immunity()
+    );
+    let vulnerable: Vec<String> = function
+        .vulnerable_inputs(MIN_CORRELATION_BIAS)
+        .iter()
+        .map(|(i, p)| format!("x{} (P = {})", i + 1, p))
+        .collect();
+    println!("Registers open to a correlation attack: {}", vulnerable.join(", "));
+}
+
+fn print_parameters(config: &AttackConfig) {
+    let degrees = config.degrees();
+    for register in config.correlated_registers() {
+        let (degree, parameters) = (degrees[register], config.parameters(register));
+        println!(
+            "LFSR{}: degree {}, N* = {}, C = {} (alpha = {}, beta = {:e}{})",
+            register + 1,
+            degree,
+            parameters.required_len,
+            parameters.threshold,
+            config.alpha,
+            config.beta.unwrap_or_else(|| 0.5f64.powi(degree as i32)),
+            if config.is_overridden(register) { ", overridden" } else { "" }
+        );
+    }
+}
+
+fn print_pairs(registers: &[usize], pairs: &[CandidatePair]) {
+    const SHOWN: usize = 10;
+    println!("{} candidate pairs (state, discrepancy):", pairs.len());
+    for pair in pairs.iter().take(SHOWN) {
+        println!(
+            "  LFSR{} {:>10} ({:>3})  LFSR{} {:>10} ({:>3})",
+            registers[0] + 1, pair.states[0], pair.discrepancies[0], registers[1] + 1, pair.states[1], pair.discrepancies[1]
+        );
+    }
+    if pairs.len() > SHOWN {
+        println!("  ... {} more", pairs.len() - SHOWN);
+    }
+}
+
+fn run_attack(config: &AttackConfig) -> Result<(), AttackError> {
+    let [degree1, degree2, degree3] = config.degrees();
+    let n = config.keystream.len();
+
+    let registers = config.correlated_registers();
+    print_combiner(&config.function);
+    print_parameters(config);
+
+    let recovery = match attack(config, Some(&report_progress)) {
+        Ok(recovery) => recovery,
+        Err(e) => {
+            match &e {
+                AttackError::NoConsistentState { candidate_pairs, .. }
+                | AttackError::Underdetermined { candidate_pairs, .. }
+                | AttackError::VerificationMismatch { candidate_pairs, .. } => {
+                    print_pairs(&registers, candidate_pairs)
+                }
+                _ => {}
+            }
+            return Err(e);
+        }
+    };
+
+    print_pairs(&registers, &recovery.candidate_pairs);
+    println!("\n{} key(s) reproduce all {} keystream bits:", recovery.keys.len(), n);
+    for key in &recovery.keys {
+        println!("\nLFSR1 state: {:10} {:0width$b}", key.lfsr1, key.lfsr1, width = degree1);
+        println!("LFSR2 state: {:10} {:0width$b}", key.lfsr2, key.lfsr2, width = degree2);
+        println!("LFSR3 state: {:10} {:0width$b}", key.lfsr3, key.lfsr3, width = degree3);
+    }
+    println!();
+
+    Ok(())
+}
+
+fn run_linear_complexity(config: &AttackConfig) {
+    let target_seq = &config.keystream;
+
+    for (i, polynomial) in config.polynomials.iter().enumerate() {
+        let mut lfsr: LFSR<BitVector> = LFSR::from_polynomial(polynomial);
+        let sequence = lfsr.generate(BitVector::from_u64(1, polynomial.degree()), target_seq.len() as u64);
+        let connection = berlekamp_massey(&sequence);
+
+        println!(
+            "LFSR{}: degree {}, linear complexity {}, recovered polynomial {} (expected {})",
+            i + 1,
+            polynomial.degree(),
+            connection.linear_complexity,
+            connection.feedback_polynomial().map_or("-".to_string(), |p| p.to_string()),
+            polynomial
+        );
+    }
+
+    let profile = linear_complexity_profile(target_seq);
+    println!("\nLinear complexity profile of the keystream:");
+    for n in (128..=profile.len()).step_by(128) {
+        println!("{:>6} bits: {}", n, profile[n - 1]);
+    }
+
+    let complexity = profile.last().copied().unwrap_or(0);
+    let observable = format!("at most n/2 = {} is observable from {} bits", target_seq.len() / 2, target_seq.len());
+    if config.function == BooleanFunction::geffe() {
+        let [l1, l2, l3] = config.degrees();
+        let expected = l1 * l3 + l2 * l3 + l2;
+        println!("\nKeystream linear complexity: {} (Geffe combiner: L1*L3 + L2*L3 + L2 = {}, {})", complexity, expected, observable);
+    } else {
+        println!("\nKeystream linear complexity: {} ({})", complexity, observable);
+    }
+}
+
+#[derive(StructOpt)]
+struct Cli {
+    /// Built-in attack variant: beta (25/26/27-bit registers) or sigma (30/31/32-bit registers)
+    #[structopt(long, default_value = "sigma")]
+    preset: String,
+    /// Attack configuration file (key = value lines), used instead of the preset
+    #[structopt(long)]
+    config: Option<String>,
+    /// Override the LFSR1 polynomial, e.g. "x^25 + x^3 + 1"
+    #[structopt(long)]
+    lfsr1: Option<String>,
+    /// Override the LFSR2 polynomial
+    #[structopt(long)]
+    lfsr2: Option<String>,
+    /// Override the LFSR3 polynomial
+    #[structopt(long)]
+    lfsr3: Option<String>,
+    /// Override the combining function: geffe, majority or a truth table such as "01010011" (entry x at position x)
+    #[structopt(long)]
+    function: Option<String>,
+    /// Read the keystream from this file instead of the one named by the config or preset
+    #[structopt(long)]
+    keystream: Option<String>,
+    /// Encoding of the --keystream file: ascii, binary or hex
+    #[structopt(long, default_value = "ascii")]
+    format: String,
+    /// Bit order of a binary keystream: msb or lsb
+    #[structopt(long, default_value = "msb")]
+    bit_order: String,
+    /// Number of bits in the --keystream file, dropping the padding of binary and hex files
+    #[structopt(long)]
+    bits: Option<usize>,
+    /// Run Berlekamp-Massey on the registers and the keystream instead of the attack
+    #[structopt(long)]
+    linear_complexity: bool,
+    /// Number of worker threads for the candidate search (0 uses all cores)
+    #[structopt(long, default_value = "0")]
+    threads: usize,
+    /// Probability of rejecting the right state, used to compute N* and C (default 0.01)
+    #[structopt(long)]
+    alpha: Option<f64>,
+    /// Probability of accepting a wrong state (defaults to 2^-degree)
+    #[structopt(long)]
+    beta: Option<f64>,
+    /// Override the number of keystream bits N* checked for LFSR1
+    #[structopt(long)]
+    lfsr1_required_len: Option<usize>,
+    /// Override the discrepancy threshold C for LFSR1
+    #[structopt(long)]
+    lfsr1_threshold: Option<usize>,
+    /// Override the number of keystream bits N* checked for LFSR2
+    #[structopt(long)]
+    lfsr2_required_len: Option<usize>,
+    /// Override the discrepancy threshold C for LFSR2
+    #[structopt(long)]
+    lfsr2_threshold: Option<usize>,
+    /// Override the number of keystream bits N* checked for LFSR3
+    #[structopt(long)]
+    lfsr3_required_len: Option<usize>,
+    /// Override the discrepancy threshold C for LFSR3
+    #[structopt(long)]
+    lfsr3_threshold: Option<usize>,
+}
+
+fn build_config(args: &Cli) -> Result<AttackConfig, String> {
+    let file = match &args.config {
+        Some(file) => file.clone(),
+        None => preset_file(&args.preset).ok_or_else(|| format!("unknown preset '{}'", args.preset))?,
+    };
+    let mut config = AttackConfig::load(&file).map_err(|e| format!("{}: {}", file, e))?;
+
+    for (i, polynomial) in [&args.lfsr1, &args.lfsr2, &args.lfsr3].iter().enumerate() {
+        if let Some(polynomial) = polynomial {
+            config.polynomials[i] = FeedbackPolynomial::parse(polynomial).ok_or_else(|| format!("invalid polynomial '{}'", polynomial))?;
+        }
+    }
+    if let Some(function) = &args.function {
+        config.function = parse_function(function).ok_or_else(|| format!("invalid combining function '{}'", function))?;
+    }
+    if let Some(file) = &args.keystream {
+        let format = BitFormat::from_name(&args.format).ok_or_else(|| format!("unknown format '{}'", args.format))?;
+        let order = BitOrder::from_name(&args.bit_order).ok_or_else(|| format!("unknown bit order '{}'", args.bit_order))?;
+        config.keystream = read_bits(file, format, order, args.bits).map_err(|e| format!("{}: {}", file, e))?;
+    }
+
+    for (name, value) in [("alpha", args.alpha), ("beta", args.beta)] {
+        if let Some(p) = value.filter(|&p| !(p > 0.0 && p < 1.0)) {
+            return Err(format!("--{} must lie in (0, 1), got {}", name, p));
+        }
+    }
+    for (register, required_len) in [args.lfsr1_required_len, args.lfsr2_required_len, args.lfsr3_required_len].iter().enumerate() {
+        if *required_len == Some(0) {
+            return Err(format!("--lfsr{}-required-len must be positive", register + 1));
+        }
+    }
+    config.alpha = args.alpha.unwrap_or(config.alpha);
+    config.beta = args.beta.or(config.beta);
+    config.required_len = [
+        args.lfsr1_required_len.or(config.required_len[0]),
+        args.lfsr2_required_len.or(config.required_len[1]),
+        args.lfsr3_required_len.or(config.required_len[2]),
+    ];
+    config.threshold = [
+        args.lfsr1_threshold.or(config.threshold[0]),
+        args.lfsr2_threshold.or(config.threshold[1]),
+        args.lfsr3_threshold.or(config.threshold[2]),
+    ];
+
+    Ok(config)
+}
+
+fn main() {
+    let args = Cli::from_args();
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(args.threads)
+        .build_global()
+        .expect("Failed to start the thread pool.");
+
+    let config = match build_config(&args) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let start = Instant::now();
+
+    if args.linear_complexity {
+        run_linear_complexity(&config);
+    } else if let Err(e) = run_attack(&config) {
+        eprintln!("Attack failed: {}", e);
+        std::process::exit(2);
+    }
+
+    let duration = start.elapsed();
+    println!("Execution time: {} seconds", duration.as_secs());
+}
@@ -0,0 +1,111 @@
+use std::io;
+use structopt::StructOpt;
+
+use symmetric_crypto::bitstream::{write_bits, BitFormat, BitOrder};
+use symmetric_crypto::geffe::Geffe;
+use symmetric_crypto::generators::{
+    AlternatingStepGenerator, KeystreamGenerator, MajorityClockedGenerator, SelfShrinkingGenerator, ShrinkingGenerator,
+    ThresholdGenerator,
+};
+use symmetric_crypto::lfsr::LFSR;
+use symmetric_crypto::polynomial::FeedbackPolynomial;
+use symmetric_crypto::rng::XorShift64;
+
+// primitive polynomials of the registers the generators are built from
+const POLYNOMIALS: [&str; 5] = [
+    "x^25 + x^3 + 1",
+    "x^26 + x^6 + x^2 + x + 1",
+    "x^27 + x^5 + x^2 + x + 1",
+    "x^29 + x^2 + 1",
+    "x^31 + x^3 + 1",
+];
+
+const GENERATORS: &str = "geffe, threshold3, threshold5, shrinking, self-shrinking, alternating-step, majority";
+
+#[derive(StructOpt)]
+struct Cli {
+    /// One of: geffe, threshold3, threshold5, shrinking, self-shrinking, alternating-step, majority
+    #[structopt(long, default_value = "geffe")]
+    generator: String,
+    /// Number of keystream bits to generate
+    #[structopt(long, default_value = "2048")]
+    length: usize,
+    /// File to write the bits to
+    #[structopt(long, default_value = "keystream.txt")]
+    output: String,
+    /// Encoding of the output file: ascii, binary or hex
+    #[structopt(long, default_value = "ascii")]
+    format: String,
+    /// Bit order of a binary output file: msb or lsb
+    #[structopt(long, default_value = "msb")]
+    bit_order: String,
+    /// Seed the register states are derived from
+    #[structopt(long, default_value = "1")]
+    seed: u64,
+}
+
+// Registers with non-zero states derived from the seed by xorshift64.
+fn registers(seed: u64) -> Vec<LFSR<u64>> {
+    let mut rng = XorShift64::new(seed);
+    POLYNOMIALS
+        .iter()
+        .map(|polynomial| {
+            let mut lfsr = LFSR::from_polynomial(&FeedbackPolynomial::parse(polynomial).unwrap());
+            let mask = (1u64 << lfsr.degree()) - 1;
+            let mut state = 0;
+            while state == 0 {
+                state = rng.next_u64() & mask;
+            }
+            lfsr.set_state(state);
+            lfsr
+        })
+        .collect()
+}
+
+fn build_generator(name: &str, seed: u64) -> Option<Box<dyn KeystreamGenerator>> {
+    let mut r = registers(seed);
+    let generator: Box<dyn KeystreamGenerator> = match name {
+        "geffe" => Box::new(Geffe::new(r[0].clone(), r[1].clone(), r[2].clone())),
+        "threshold3" => Box::new(ThresholdGenerator::new(r.drain(..3).collect())),
+        "threshold5" => Box::new(ThresholdGenerator::new(r)),
+        "shrinking" => Box::new(ShrinkingGenerator::new(r[0].clone(), r[1].clone())),
+        "self-shrinking" => Box::new(SelfShrinkingGenerator::new(r[0].clone())),
+        "alternating-step" => Box::new(AlternatingStepGenerator::new(r[0].clone(), r[1].clone(), r[2].clone())),
+        "majority" => {
+            let registers = r.drain(..3).map(|lfsr| {
+                let clocking_bit = lfsr.degree() / 2;
+                (lfsr, clocking_bit)
+            });
+            Box::new(MajorityClockedGenerator::new(registers.collect()))
+        }
+        _ => return None,
+    };
+    Some(generator)
+}
+
+fn main() -> io::Result<()> {
+    let args = Cli::from_args();
+    let Some(mut generator) = build_generator(&args.generator, args.seed) else {
+        eprintln!("unknown generator '{}', expected one of: {}", args.generator, GENERATORS);
+        std::process::exit(1);
+    };
+
+    let (Some(format), Some(order)) = (BitFormat::from_name(&args.format), BitOrder::from_name(&args.bit_order)) else {
+        eprintln!("unknown format '{}' or bit order '{}'", args.format, args.bit_order);
+        std::process::exit(1);
+    };
+
+    let bits = generator.keystream(args.length);
+    write_bits(&args.output, &bits, format, order)?;
+    println!("Wrote {} bits of the {} generator to {}", bits.len(), args.generator, args.output);
+    let padded = match format {
+        BitFormat::Ascii => false,
+        BitFormat::Binary => bits.len() % 8 != 0,
+        BitFormat::Hex => bits.len() % 4 != 0,
+    };
+    if padded {
+        println!("The last {} is zero-padded; read the file back with --bits {}", if format == BitFormat::Hex { "digit" } else { "byte" }, bits.len());
+    }
+
+    Ok(())
+}
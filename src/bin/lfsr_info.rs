@@ -0,0 +1,90 @@
+use std::io::{self};
+use structopt::StructOpt;
+
+use symmetric_crypto::gf2::{factor, is_irreducible, is_primitive, is_primitive_with_factors, period, Gf2Polynomial};
+use symmetric_crypto::polynomial::FeedbackPolynomial;
+
+#[derive(StructOpt)]
+struct Cli {
+    /// Feedback polynomial as "x^25 + x^3 + 1", an exponent list "25,3,0" or hex "0x2000009"
+    #[structopt(long)]
+    polynomial: Option<String>,
+    /// LFSR taps bitmask (decimal or 0x-prefixed hex), used together with --degree
+    #[structopt(long)]
+    taps: Option<String>,
+    #[structopt(long)]
+    degree: Option<usize>,
+    /// Prime factors of 2^degree - 1, required for primitivity above degree 64
+    #[structopt(long, use_delimiter = true)]
+    factors: Vec<u128>,
+}
+
+fn invalid_input(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+fn parse_taps(taps: &str) -> Option<u128> {
+    match taps.strip_prefix("0x") {
+        Some(hex) => u128::from_str_radix(hex, 16).ok(),
+        None => taps.parse().ok(),
+    }
+}
+
+fn main() -> io::Result<()> {
+    let args = Cli::from_args();
+
+    let polynomial = match (&args.polynomial, &args.taps, args.degree) {
+        (Some(polynomial), _, _) => FeedbackPolynomial::parse(polynomial)
+            .ok_or_else(|| invalid_input(format!("invalid polynomial '{}'", polynomial)))?,
+        (None, Some(taps), Some(degree)) => {
+            let mask = parse_taps(taps).ok_or_else(|| invalid_input(format!("invalid taps '{}'", taps)))?;
+            // the mask is a u128, so wider registers and taps at or above the degree cannot be expressed
+            if degree == 0 || degree > 128 {
+                return Err(invalid_input(format!("degree must be between 1 and 128, got {}", degree)));
+            }
+            if degree < 128 && mask >> degree != 0 {
+                return Err(invalid_input(format!("taps '{}' have bits at or above the degree {}", taps, degree)));
+            }
+            FeedbackPolynomial::from_taps(&mask, degree)
+                .ok_or_else(|| invalid_input("taps must include bit 0 and fit below the degree".to_string()))?
+        }
+        _ => return Err(invalid_input("pass --polynomial or both --taps and --degree".to_string())),
+    };
+
+    let f = Gf2Polynomial::from_feedback(&polynomial);
+    let degree = polynomial.degree();
+
+    println!("Polynomial: {}", polynomial);
+    println!("Hex: {}", polynomial.to_hex());
+    println!("Degree: {}", degree);
+    println!("Irreducible: {}", is_irreducible(&f));
+
+    let primitive = if args.factors.is_empty() {
+        is_primitive(&f)
+    } else {
+        is_primitive_with_factors(&f, &args.factors)
+    };
+    match primitive {
+        Some(primitive) => println!("Primitive: {}", primitive),
+        None if args.factors.is_empty() => println!("Primitive: unknown (pass --factors of 2^{} - 1)", degree),
+        None => println!("Primitive: unknown (the --factors are not the prime factors of 2^{} - 1)", degree),
+    }
+
+    if degree <= 64 {
+        let factors: Vec<String> = factor(&f)
+            .iter()
+            .map(|(g, multiplicity)| if *multiplicity > 1 { format!("({})^{}", g, multiplicity) } else { format!("({})", g) })
+            .collect();
+        println!("Factorization: {}", factors.join(" * "));
+    }
+
+    match period(&f) {
+        Some(period) => println!("Period: {}", period),
+        None => println!("Period: unknown"),
+    }
+    if degree < 128 {
+        println!("Maximum period: {}", (1u128 << degree) - 1);
+    }
+
+    Ok(())
+}
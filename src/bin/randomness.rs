@@ -0,0 +1,102 @@
+use structopt::StructOpt;
+
+use symmetric_crypto::geffe::Geffe;
+use symmetric_crypto::bitstream::{read_bits, BitFormat, BitOrder};
+use symmetric_crypto::lfsr::LFSR;
+use symmetric_crypto::polynomial::FeedbackPolynomial;
+use symmetric_crypto::randomness::{run_all, SIGNIFICANCE};
+
+#[derive(StructOpt)]
+struct Cli {
+    /// Test the bits of this file instead of generating them
+    #[structopt(long)]
+    input: Option<String>,
+    /// Encoding of the input file: ascii, binary or hex
+    #[structopt(long, default_value = "ascii")]
+    format: String,
+    /// Bit order of a binary input file: msb or lsb
+    #[structopt(long, default_value = "msb")]
+    bit_order: String,
+    /// Number of bits in the input file, dropping the padding of binary and hex files
+    #[structopt(long)]
+    bits: Option<usize>,
+    /// Generator to test when no input is given: lfsr or geffe
+    #[structopt(long, default_value = "geffe")]
+    source: String,
+    /// Feedback polynomial of the lfsr source
+    #[structopt(long, default_value = "x^31 + x^3 + 1")]
+    polynomial: String,
+    /// Number of bits to generate
+    #[structopt(long, default_value = "100000")]
+    length: usize,
+    /// Initial state of the generated registers, truncated to each register's degree (a sparse state
+    /// such as 1 makes the first few hundred bits of a sparse register mostly zeros)
+    #[structopt(long, default_value = "0x5a3c96e1")]
+    seed: String,
+}
+
+fn parse_seed(seed: &str) -> Option<u64> {
+    match seed.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => seed.parse().ok(),
+    }
+}
+
+fn generate(args: &Cli) -> Result<Vec<u8>, String> {
+    let seed = parse_seed(&args.seed).ok_or_else(|| format!("invalid seed '{}'", args.seed))?;
+    let register = |polynomial: &str| -> Result<(LFSR<u64>, u64), String> {
+        let polynomial = FeedbackPolynomial::parse(polynomial).ok_or_else(|| format!("invalid polynomial '{}'", polynomial))?;
+        if polynomial.degree() > 64 {
+            return Err(format!("the {}-bit register does not fit the 64-bit seed", polynomial.degree()));
+        }
+        let lfsr = LFSR::from_polynomial(&polynomial);
+        let state = seed & (u64::MAX >> (64 - lfsr.degree()));
+        if state == 0 {
+            return Err(format!("the seed leaves the {}-bit register all zero", lfsr.degree()));
+        }
+        Ok((lfsr, state))
+    };
+
+    match args.source.as_str() {
+        "lfsr" => {
+            let (mut lfsr, state) = register(&args.polynomial)?;
+            Ok(lfsr.generate(state, args.length as u64))
+        }
+        "geffe" => {
+            let (lfsr1, state1) = register("x^25 + x^3 + 1")?;
+            let (lfsr2, state2) = register("x^26 + x^6 + x^2 + x + 1")?;
+            let (lfsr3, state3) = register("x^27 + x^5 + x^2 + x + 1")?;
+            Ok(Geffe::new(lfsr1, lfsr2, lfsr3).generate(state1, state2, state3, args.length))
+        }
+        source => Err(format!("unknown source '{}', expected lfsr or geffe", source)),
+    }
+}
+
+fn read_input(args: &Cli, file: &str) -> Result<Vec<u8>, String> {
+    let format = BitFormat::from_name(&args.format).ok_or_else(|| format!("unknown format '{}'", args.format))?;
+    let order = BitOrder::from_name(&args.bit_order).ok_or_else(|| format!("unknown bit order '{}'", args.bit_order))?;
+    read_bits(file, format, order, args.bits).map_err(|e| format!("{}: {}", file, e))
+}
+
+fn main() {
+    let args = Cli::from_args();
+    let bits = match &args.input {
+        Some(file) => read_input(&args, file),
+        None => generate(&args),
+    };
+    let bits = bits.unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    });
+
+    println!("Testing {} bits at significance level {}", bits.len(), SIGNIFICANCE);
+    for result in run_all(&bits) {
+        let p_values: Vec<String> = result.p_values.iter().map(|p| format!("{:.6}", p)).collect();
+        println!(
+            "{:<22} {:<20} {}",
+            result.name,
+            p_values.join(", "),
+            if result.passed(SIGNIFICANCE) { "PASS" } else { "FAIL" }
+        );
+    }
+}
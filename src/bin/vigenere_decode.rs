@@ -0,0 +1,141 @@
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+use structopt::StructOpt;
+
+use symmetric_crypto::alphabet::Alphabet;
+use symmetric_crypto::fitness::{choose_best_key, NgramScorer};
+use symmetric_crypto::kasiski::{combined_key_length_ranking, kasiski};
+use symmetric_crypto::profile::LanguageProfile;
+use symmetric_crypto::refine::refine_key;
+use symmetric_crypto::text::process_file;
+use symmetric_crypto::vigenere::{compute_r, crack_key_mi, determine_key_characters, select_key_length};
+
+const INPUT_FILE: &str = "../../text_files/vigenere_cipher/to_decode/input.txt";
+const PREPROCESSED_FILE: &str = "../../text_files/vigenere_cipher/to_decode/preprocessed.txt";
+const DECODED_FILE_PATH: &str = "../../text_files/vigenere_cipher/to_decode/decoded.txt";
+const PROFILE_FILE: &str = "../../text_files/profiles/russian_32.txt";
+const FITNESS_CORPUS_FILE: &str = "../../text_files/entropy/boloto.txt";
+
+#[derive(StructOpt)]
+struct Cli {
+    /// Language profile with letter frequencies of the plaintext language
+    #[structopt(long, default_value = PROFILE_FILE)]
+    profile: String,
+    /// Key length estimator: ic, kasiski or combined
+    #[structopt(long, default_value = "ic")]
+    key_length_method: String,
+    /// Smallest key length to consider
+    #[structopt(long, default_value = "2")]
+    min_r: usize,
+    /// Largest key length to consider
+    #[structopt(long, default_value = "20")]
+    max_r: usize,
+    /// Reference corpus used to train the n-gram fitness scorer
+    #[structopt(long, default_value = FITNESS_CORPUS_FILE)]
+    fitness_corpus: String,
+    /// N-gram size of the fitness scorer (at least 1)
+    #[structopt(long, default_value = "4")]
+    fitness_n: usize,
+    /// Score candidate keys with the bigrams of the language profile instead of the corpus
+    #[structopt(long)]
+    fitness_from_profile: bool,
+    /// Number of hill-climbing mutations tried on the m_i key
+    #[structopt(long, default_value = "1000")]
+    refine_iterations: usize,
+    /// Seed of the hill-climbing mutation sequence
+    #[structopt(long, default_value = "1")]
+    seed: u64,
+}
+
+fn write_decoded(decoded_text: &str) -> io::Result<()> {
+    let path = Path::new(DECODED_FILE_PATH);
+    let mut file = File::create(path)?;
+    file.write_all(decoded_text.as_bytes())?;
+
+    Ok(())
+}
+
+fn main() -> io::Result<()> {
+    let args = Cli::from_args();
+    if args.fitness_n == 0 {
+        eprintln!("--fitness-n must be at least 1");
+        std::process::exit(1);
+    }
+    if args.min_r == 0 || args.min_r > args.max_r {
+        eprintln!("key lengths must satisfy 1 <= --min-r <= --max-r");
+        std::process::exit(1);
+    }
+
+    let alphabet = Alphabet::russian_32();
+    let profile = LanguageProfile::load(&args.profile)?;
+    profile.check_alphabet(&alphabet)?;
+
+    let text = process_file(INPUT_FILE, PREPROCESSED_FILE, false, &alphabet)?;
+    println!("Text processing completed.");
+
+    println!("expected i: {}", profile.expected_coincidence());
+    let candidates = compute_r(&text, &profile, args.min_r, args.max_r);
+    println!("{:>4} | {:>10} | {:>10}", "r", "average i", "deviation");
+    for c in &candidates {
+        println!("{:>4} | {:>10.6} | {:>10.6}", c.length, c.average_coincidence, c.deviation);
+    }
+    println!();
+
+    let evidence = combined_key_length_ranking(&text, &profile, args.min_r, args.max_r);
+    println!("{:>4} | {:>8} | {:>10} | {:>8} | {:>8}", "r", "kasiski", "average i", "i score", "combined");
+    for e in &evidence {
+        println!(
+            "{:>4} | {:>8.4} | {:>10.6} | {:>8.4} | {:>8.4}",
+            e.length, e.kasiski_score, e.coincidence, e.coincidence_score, e.combined_score
+        );
+    }
+
+    let r = match args.key_length_method.as_str() {
+        "ic" => select_key_length(&candidates, &profile),
+        "kasiski" => kasiski(&text, args.min_r, args.max_r).first().map(|score| score.length),
+        "combined" => evidence.first().map(|e| e.length),
+        method => {
+            eprintln!("unknown key length method '{}', expected ic, kasiski or combined", method);
+            std::process::exit(1);
+        }
+    }
+    .expect("Failed to compute key length.");
+    println!("Optimal key length (r) found: {}", r);
+
+    let key_frequency = determine_key_characters(&text, r, &profile);
+    println!("Key determined by frequency method: {}", key_frequency);
+
+    let key_mi = crack_key_mi(&text, r, &profile);
+    println!("Key determined by m_i method: {}", key_mi);
+
+    let scorer = if args.fitness_from_profile {
+        NgramScorer::from_profile(&profile)
+    } else {
+        NgramScorer::from_corpus_file(&args.fitness_corpus, &alphabet, args.fitness_n)?
+    };
+    let refinement = refine_key(&text, &key_mi, &alphabet, &scorer, args.refine_iterations, args.seed);
+    for improvement in &refinement.improvements {
+        println!(
+            "Refinement iteration {}: key {} fitness {:.4}",
+            improvement.iteration, improvement.key, improvement.score
+        );
+    }
+    println!("Key after refinement: {}", refinement.key);
+
+    let selection = choose_best_key(&text, &[key_frequency, key_mi, refinement.key], &alphabet, &scorer);
+    for candidate in &selection.candidates {
+        println!("Fitness of key {}: {:.4}", candidate.key, candidate.score);
+    }
+
+    let best = &selection.candidates[0];
+    match selection.margin {
+        Some(margin) => println!("Best key: {} (confidence margin {:.4})", best.key, margin),
+        None => println!("Best key: {} (the only candidate)", best.key),
+    }
+
+    write_decoded(&best.plaintext)?;
+    println!("Text deciphered using the best key and written to file.");
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use std::io::{self};
+use std::fs;
+
+use symmetric_crypto::alphabet::Alphabet;
+use symmetric_crypto::profile::LanguageProfile;
+use symmetric_crypto::text::{process_file, coincidence};
+use symmetric_crypto::vigenere::{i_m_theoretical, vigenere_encode};
+
+fn main() -> io::Result<()> {
+    let alphabet = Alphabet::russian_32();
+    let profile = LanguageProfile::load("../../text_files/profiles/russian_32.txt")?;
+    profile.check_alphabet(&alphabet)?;
+
+    let input_file = "../../text_files/vigenere_cipher/to_encode//input.txt";
+    let preprocessed_file = "../../text_files/vigenere_cipher/to_encode//preprocessed.txt";
+    let processed_text = process_file(input_file, preprocessed_file, false, &alphabet)?;
+    
+    let keys: [(&str, i8); 6] = [
+        ("оф", 2),
+        ("енз", 3),
+        ("ивац", 4),
+        ("ежпол", 5),
+        ("ьськарозвидка", 14),
+        ("дайтидокиевазатридня", 20),
+    ];
+
+    for (key, key_size) in keys {
+        let encoded_file_name = format!(
+            "../../text_files/vigenere_cipher/to_encode//encoded_{}.txt",
+            key_size
+        );
+    
+        match vigenere_encode(&processed_text, &encoded_file_name, key, &alphabet) {
+            Ok(()) => println!("File encoded successfully. Encoded text saved to {}", encoded_file_name),
+            Err(e) => eprintln!("{}: {}", encoded_file_name, e),
+        }
+    }
+
+    println!{"<-- coincidence -->"};
+    let coincidence_index = 1.0 / alphabet.size() as f64;
+    println!{"theoretical I_0:  {}", coincidence_index};
+
+    let i_m = i_m_theoretical(profile.unigrams());
+    println!{"theoretical I_m:  {}", i_m};
+
+    let i_input = coincidence(&processed_text, &alphabet);
+    println!{"I for input text (message):  {}", i_input};
+
+    for (key, key_size) in keys {
+        let encoded_file_name = format!(
+            "../../text_files/vigenere_cipher/to_encode/encoded_{}.txt",
+            key_size
+        );
+
+        let encoded_text = fs::read_to_string(&encoded_file_name)?;
+        let i_encoded = coincidence(&encoded_text, &alphabet);
+        println!("I for key '{}' (size {}): {}", key, key_size, i_encoded);
+    }
+
+    Ok(())
+}
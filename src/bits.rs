@@ -0,0 +1,102 @@
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitVector {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitVector {
+    pub fn new(len: usize) -> BitVector {
+        BitVector { words: vec![0; len.div_ceil(64).max(1)], len }
+    }
+
+    pub fn from_u64(value: u64, len: usize) -> BitVector {
+        let mut bits = BitVector::new(len);
+        bits.words[0] = value;
+        bits
+    }
+
+    pub fn from_bits(bits: &[u8]) -> BitVector {
+        let mut vector = BitVector::new(bits.len());
+        for (i, &bit) in bits.iter().enumerate() {
+            if bit == 1 {
+                vector.set(i, 1);
+            }
+        }
+        vector
+    }
+
+    pub fn to_bits(&self) -> Vec<u8> {
+        (0..self.len).map(|i| self.get(i)).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    pub fn get(&self, i: usize) -> u8 {
+        ((self.words[i / 64] >> (i % 64)) & 1) as u8
+    }
+
+    pub fn set(&mut self, i: usize, bit: u8) {
+        let mask = 1u64 << (i % 64);
+        if bit == 1 {
+            self.words[i / 64] |= mask;
+        } else {
+            self.words[i / 64] &= !mask;
+        }
+    }
+
+    pub fn toggle(&mut self, i: usize) {
+        self.words[i / 64] ^= 1u64 << (i % 64);
+    }
+
+    pub fn shift_right_one(&mut self) {
+        for i in 0..self.words.len() {
+            let carry = self.words.get(i + 1).map_or(0, |&next| next << 63);
+            self.words[i] = (self.words[i] >> 1) | carry;
+        }
+    }
+
+    pub fn xor_with(&mut self, other: &BitVector) {
+        for (a, b) in self.words.iter_mut().zip(other.words.iter()) {
+            *a ^= b;
+        }
+    }
+
+    pub fn hamming_distance(&self, other: &BitVector) -> u32 {
+        self.words.iter().zip(other.words.iter()).map(|(a, b)| (a ^ b).count_ones()).sum()
+    }
+
+    // drops the oldest bit 0 and appends `bit` at the end, moving the window one position along the stream
+    // (an empty window stays empty)
+    pub fn slide(&mut self, bit: u8) {
+        self.shift_right_one();
+        if bit == 1 && self.len > 0 {
+            self.set(self.len - 1, 1);
+        }
+    }
+
+    pub fn count_ones(&self) -> u32 {
+        self.words.iter().map(|w| w.count_ones()).sum()
+    }
+
+    pub fn and_parity(&self, other: &BitVector) -> u8 {
+        let ones: u32 = self.words.iter().zip(other.words.iter()).map(|(a, b)| (a & b).count_ones()).sum();
+        (ones & 1) as u8
+    }
+}
+
+impl std::fmt::Binary for BitVector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bits: String = (0..self.len).rev().map(|i| if self.get(i) == 1 { '1' } else { '0' }).collect();
+        f.pad_integral(true, "0b", &bits)
+    }
+}
@@ -0,0 +1,149 @@
+use std::fs;
+use std::io;
+
+use crate::errors::invalid_data;
+
+// Reading and writing bit sequences (one 0/1 byte per bit) in three encodings:
+//   ascii  - '0' and '1' characters, whitespace ignored
+//   binary - packed bytes, eight bits each, in the chosen bit order; the last byte is zero-padded
+//   hex    - hex digits, four bits each with the most significant bit first, whitespace ignored
+// Binary and hex files do not record how many bits they hold, so a stream whose length is not a
+// multiple of 8 (or 4) reads back with the padding unless the length is passed to `read_bits`.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitFormat {
+    Ascii,
+    Binary,
+    Hex,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+    MsbFirst,
+    LsbFirst,
+}
+
+impl BitFormat {
+    pub fn from_name(name: &str) -> Option<BitFormat> {
+        match name {
+            "ascii" | "text" => Some(BitFormat::Ascii),
+            "binary" | "bin" => Some(BitFormat::Binary),
+            "hex" => Some(BitFormat::Hex),
+            _ => None,
+        }
+    }
+}
+
+impl BitOrder {
+    pub fn from_name(name: &str) -> Option<BitOrder> {
+        match name {
+            "msb" | "msb-first" => Some(BitOrder::MsbFirst),
+            "lsb" | "lsb-first" => Some(BitOrder::LsbFirst),
+            _ => None,
+        }
+    }
+}
+
+// Digits of a text encoding, skipping whitespace; anything else is reported with its line and column.
+fn parse_digits(text: &str, radix: u32, name: &str) -> io::Result<Vec<u32>> {
+    let mut digits = Vec::new();
+    for (line_number, line) in text.lines().enumerate() {
+        for (column, c) in line.chars().enumerate() {
+            if c.is_whitespace() {
+                continue;
+            }
+            let digit = c.to_digit(radix).ok_or_else(|| {
+                invalid_data(format!("line {}, column {}: unexpected character '{}' in {} input", line_number + 1, column + 1, c, name))
+            })?;
+            digits.push(digit);
+        }
+    }
+    Ok(digits)
+}
+
+pub fn parse_ascii(text: &str) -> io::Result<Vec<u8>> {
+    Ok(parse_digits(text, 2, "ascii")?.into_iter().map(|d| d as u8).collect())
+}
+
+pub fn parse_hex(text: &str) -> io::Result<Vec<u8>> {
+    let text = text.trim_start();
+    let text = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")).unwrap_or(text);
+    Ok(parse_digits(text, 16, "hex")?
+        .into_iter()
+        .flat_map(|d| (0..4).rev().map(move |i| ((d >> i) & 1) as u8))
+        .collect())
+}
+
+pub fn unpack(bytes: &[u8], order: BitOrder) -> Vec<u8> {
+    bytes
+        .iter()
+        .flat_map(|&byte| {
+            (0..8).map(move |i| match order {
+                BitOrder::MsbFirst => (byte >> (7 - i)) & 1,
+                BitOrder::LsbFirst => (byte >> i) & 1,
+            })
+        })
+        .collect()
+}
+
+pub fn pack(bits: &[u8], order: BitOrder) -> Vec<u8> {
+    bits.chunks(8)
+        .map(|chunk| {
+            chunk.iter().enumerate().fold(0u8, |byte, (i, &bit)| match order {
+                BitOrder::MsbFirst => byte | ((bit & 1) << (7 - i)),
+                BitOrder::LsbFirst => byte | ((bit & 1) << i),
+            })
+        })
+        .collect()
+}
+
+pub fn decode(data: &[u8], format: BitFormat, order: BitOrder) -> io::Result<Vec<u8>> {
+    match format {
+        BitFormat::Binary => Ok(unpack(data, order)),
+        BitFormat::Ascii | BitFormat::Hex => {
+            let text = std::str::from_utf8(data).map_err(|e| invalid_data(format!("input is not text: {}", e)))?;
+            if format == BitFormat::Ascii {
+                parse_ascii(text)
+            } else {
+                parse_hex(text)
+            }
+        }
+    }
+}
+
+// Hex output is zero-padded to a multiple of four bits, binary output to a multiple of eight.
+pub fn encode(bits: &[u8], format: BitFormat, order: BitOrder) -> Vec<u8> {
+    match format {
+        BitFormat::Ascii => bits.iter().map(|&b| b'0' + (b & 1)).chain(std::iter::once(b'\n')).collect(),
+        BitFormat::Binary => pack(bits, order),
+        BitFormat::Hex => bits
+            .chunks(4)
+            .map(|chunk| {
+                let digit = chunk.iter().enumerate().fold(0u32, |acc, (i, &b)| acc | ((b as u32 & 1) << (3 - i)));
+                std::char::from_digit(digit, 16).unwrap() as u8
+            })
+            .chain(std::iter::once(b'\n'))
+            .collect(),
+    }
+}
+
+// Keeps the first `length` bits, failing if there are fewer.
+pub fn truncate(mut bits: Vec<u8>, length: usize) -> io::Result<Vec<u8>> {
+    if bits.len() < length {
+        return Err(invalid_data(format!("expected {} bits, found {}", length, bits.len())));
+    }
+    bits.truncate(length);
+    Ok(bits)
+}
+
+pub fn read_bits(path: &str, format: BitFormat, order: BitOrder, length: Option<usize>) -> io::Result<Vec<u8>> {
+    let bits = decode(&fs::read(path)?, format, order)?;
+    match length {
+        Some(length) => truncate(bits, length),
+        None => Ok(bits),
+    }
+}
+
+pub fn write_bits(path: &str, bits: &[u8], format: BitFormat, order: BitOrder) -> io::Result<()> {
+    fs::write(path, encode(bits, format, order))
+}
@@ -0,0 +1,157 @@
+use std::fmt;
+
+// A Boolean function of n variables given by its truth table: entry x holds f at the point whose
+// variable i is bit i of x (variable i is printed as x{i+1}).
+// 2^24 truth table entries; spectra of larger functions no longer fit comfortably in memory
+pub const MAX_VARIABLES: usize = 24;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BooleanFunction {
+    variables: usize,
+    truth_table: Vec<u8>,
+}
+
+impl BooleanFunction {
+    pub fn new(variables: usize, truth_table: Vec<u8>) -> Option<BooleanFunction> {
+        if variables > MAX_VARIABLES || truth_table.len() != 1 << variables || truth_table.iter().any(|&b| b > 1) {
+            return None;
+        }
+        Some(BooleanFunction { variables, truth_table })
+    }
+
+    pub fn from_fn(variables: usize, f: impl Fn(&[u8]) -> u8) -> BooleanFunction {
+        assert!(variables <= MAX_VARIABLES, "truth tables are limited to {} variables", MAX_VARIABLES);
+        let truth_table = (0..1usize << variables)
+            .map(|x| {
+                let inputs: Vec<u8> = (0..variables).map(|i| ((x >> i) & 1) as u8).collect();
+                f(&inputs) & 1
+            })
+            .collect();
+        BooleanFunction { variables, truth_table }
+    }
+
+    // x3 ? x1 : x2
+    pub fn geffe() -> BooleanFunction {
+        BooleanFunction::from_fn(3, |x| if x[2] == 1 { x[0] } else { x[1] })
+    }
+
+    pub fn majority(variables: usize) -> BooleanFunction {
+        BooleanFunction::from_fn(variables, |x| (2 * x.iter().map(|&b| b as usize).sum::<usize>() > variables) as u8)
+    }
+
+    pub fn variables(&self) -> usize {
+        self.variables
+    }
+
+    pub fn truth_table(&self) -> &[u8] {
+        &self.truth_table
+    }
+
+    pub fn evaluate(&self, inputs: &[u8]) -> u8 {
+        let index = inputs.iter().enumerate().fold(0, |acc, (i, &b)| acc | ((b as usize & 1) << i));
+        self.truth_table[index]
+    }
+
+    pub fn is_balanced(&self) -> bool {
+        2 * self.truth_table.iter().filter(|&&b| b == 1).count() == self.truth_table.len()
+    }
+
+    // Algebraic normal form by the Möbius transform: entry u is the coefficient of the monomial
+    // made of the variables set in u.
+    pub fn anf(&self) -> Vec<u8> {
+        let mut coefficients = self.truth_table.clone();
+        for i in 0..self.variables {
+            for x in 0..coefficients.len() {
+                if (x >> i) & 1 == 1 {
+                    coefficients[x] ^= coefficients[x ^ (1 << i)];
+                }
+            }
+        }
+        coefficients
+    }
+
+    pub fn algebraic_degree(&self) -> usize {
+        self.anf()
+            .iter()
+            .enumerate()
+            .filter(|&(_, &c)| c == 1)
+            .map(|(u, _)| u.count_ones() as usize)
+            .max()
+            .unwrap_or(0)
+    }
+
+    // W(a) = sum over x of (-1)^(f(x) + a.x), by the fast Walsh–Hadamard transform
+    pub fn walsh_spectrum(&self) -> Vec<i64> {
+        let mut spectrum: Vec<i64> = self.truth_table.iter().map(|&b| 1 - 2 * b as i64).collect();
+        let mut half = 1;
+        while half < spectrum.len() {
+            for block in (0..spectrum.len()).step_by(2 * half) {
+                for x in block..block + half {
+                    let (a, b) = (spectrum[x], spectrum[x + half]);
+                    spectrum[x] = a + b;
+                    spectrum[x + half] = a - b;
+                }
+            }
+            half *= 2;
+        }
+        spectrum
+    }
+
+    // distance to the nearest affine function
+    pub fn nonlinearity(&self) -> usize {
+        let max = self.walsh_spectrum().iter().map(|w| w.unsigned_abs()).max().unwrap_or(0) as usize;
+        (self.truth_table.len() - max) / 2
+    }
+
+    // Largest m such that the output is independent of every set of at most m inputs, i.e. the
+    // Walsh coefficients vanish for all masks of weight 1..=m.
+    pub fn correlation_immunity(&self) -> usize {
+        let spectrum = self.walsh_spectrum();
+        (1..=self.variables)
+            .take_while(|&m| spectrum.iter().enumerate().all(|(a, &w)| a.count_ones() as usize != m || w == 0))
+            .count()
+    }
+
+    // P(f(x) = x_i) over uniform inputs: 1/2 + W(e_i) / 2^(n+1)
+    pub fn input_correlation(&self, variable: usize) -> f64 {
+        let w = self.walsh_spectrum()[1 << variable];
+        0.5 + w as f64 / (2 * self.truth_table.len()) as f64
+    }
+
+    pub fn input_correlations(&self) -> Vec<f64> {
+        (0..self.variables).map(|i| self.input_correlation(i)).collect()
+    }
+
+    // Inputs whose correlation with the output differs from 1/2 by at least `min_bias`, most
+    // biased first; these registers can be attacked on their own.
+    pub fn vulnerable_inputs(&self, min_bias: f64) -> Vec<(usize, f64)> {
+        let mut inputs: Vec<(usize, f64)> = self
+            .input_correlations()
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, p)| (p - 0.5).abs() >= min_bias)
+            .collect();
+        inputs.sort_by(|a, b| (b.1 - 0.5).abs().total_cmp(&(a.1 - 0.5).abs()));
+        inputs
+    }
+}
+
+// The algebraic normal form, e.g. "x1x3 + x2x3 + x2"
+impl fmt::Display for BooleanFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut monomials: Vec<usize> = self.anf().iter().enumerate().filter(|&(_, &c)| c == 1).map(|(u, _)| u).collect();
+        if monomials.is_empty() {
+            return write!(f, "0");
+        }
+
+        monomials.sort_by_key(|&u| (std::cmp::Reverse(u.count_ones()), u));
+        let terms: Vec<String> = monomials
+            .iter()
+            .map(|&u| match u {
+                0 => "1".to_string(),
+                _ => (0..self.variables).filter(|&i| (u >> i) & 1 == 1).map(|i| format!("x{}", i + 1)).collect(),
+            })
+            .collect();
+        write!(f, "{}", terms.join(" + "))
+    }
+}
@@ -0,0 +1,6 @@
+use std::io;
+
+// Malformed input files (profiles, configs, bit streams) are reported as InvalidData.
+pub fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
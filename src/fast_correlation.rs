@@ -0,0 +1,192 @@
+use std::collections::BTreeSet;
+
+use crate::bits::BitVector;
+use crate::lfsr::{RegisterState, LFSR};
+use crate::linear_system::{LinearSystem, Solution};
+
+// Fast correlation attack after Meier and Staffelbach (algorithm B): the keystream is treated as the
+// register output sent through a binary symmetric channel and decoded with the register's own
+// linear relations, so the cost depends on the keystream length rather than on 2^degree.
+
+// below this many squared checks per keystream bit the attack adds combined ones
+const MIN_CHECKS_PER_BIT: usize = 16;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FastCorrelationResult<S> {
+    pub state: S,
+    // share of keystream bits equal to the register output of the recovered state
+    pub agreement: f64,
+    pub rounds: usize,
+}
+
+// Linear relations of the output sequence, as sorted exponent lists starting at 0. Squaring gives
+// f(x^(2^k)) = f(x)^(2^k); combining adds two relations (one of them shifted) so that a shared
+// term cancels, which for a characteristic polynomial of weight w yields relations of weight up to
+// 2w - 2 (combining is skipped unless `combine` is set). Only relations spanning fewer than `length`
+// bits are kept.
+fn relations(exponents: &[usize], length: usize, combine: bool) -> Vec<Vec<usize>> {
+    let mut base: Vec<usize> = exponents.to_vec();
+    base.sort_unstable();
+    let span = base.last().copied().unwrap_or(0);
+    if span == 0 {
+        return Vec::new();
+    }
+
+    let mut squared = Vec::new();
+    let mut scale = 1;
+    while span * scale < length {
+        squared.push(base.iter().map(|&e| e * scale).collect::<Vec<usize>>());
+        scale *= 2;
+    }
+
+    let mut relations: BTreeSet<Vec<usize>> = squared.iter().cloned().collect();
+    if !combine {
+        return relations.into_iter().collect();
+    }
+
+    let max_weight = 2 * base.len() - 2;
+    for (i, g) in squared.iter().enumerate() {
+        for h in &squared[i..] {
+            for &eg in g {
+                for &eh in h.iter().filter(|&&eh| eh <= eg) {
+                    let shift = eg - eh;
+                    let mut sum: BTreeSet<usize> = g.iter().copied().collect();
+                    for e in h.iter().map(|&e| e + shift) {
+                        if !sum.remove(&e) {
+                            sum.insert(e);
+                        }
+                    }
+
+                    let Some(&low) = sum.first() else { continue };
+                    let relation: Vec<usize> = sum.iter().map(|&e| e - low).collect();
+                    if relation.len() <= max_weight && relation.last().is_some_and(|&span| span < length) {
+                        relations.insert(relation);
+                    }
+                }
+            }
+        }
+    }
+
+    relations.into_iter().collect()
+}
+
+// Every relation holds for the output sequence at every shift t. Each check is the list of positions
+// whose bits must XOR to zero; only checks within `length` bits are kept.
+pub fn parity_checks(exponents: &[usize], length: usize, combine: bool) -> Vec<Vec<usize>> {
+    let mut checks = Vec::new();
+    for relation in relations(exponents, length, combine) {
+        let span = relation.last().copied().unwrap_or(0);
+        for t in 0..length - span {
+            checks.push(relation.iter().map(|&e| t + e).collect());
+        }
+    }
+    checks
+}
+
+// Posterior probability that every bit is correct given the checks, starting from `prior` for all
+// bits and refining the estimates `iterations` times.
+fn posteriors(bits: &[u8], checks: &[Vec<usize>], bit_checks: &[Vec<usize>], prior: f64, iterations: usize) -> Vec<f64> {
+    let mut probabilities = vec![prior; bits.len()];
+
+    for _ in 0..iterations {
+        let signs: Vec<f64> = probabilities.iter().map(|&p| 2.0 * p - 1.0).collect();
+        let satisfied: Vec<bool> = checks.iter().map(|check| check.iter().fold(0, |acc, &i| acc ^ bits[i]) == 0).collect();
+
+        probabilities = (0..bits.len())
+            .map(|n| {
+                let mut log_odds = (prior / (1.0 - prior)).ln();
+                for &c in &bit_checks[n] {
+                    // probability that the other bits of the check are, taken together, correct
+                    let others: f64 = checks[c].iter().filter(|&&i| i != n).map(|&i| signs[i]).product();
+                    let s = ((1.0 + others) / 2.0).clamp(1e-12, 1.0 - 1e-12);
+                    let ratio = (s / (1.0 - s)).ln();
+                    log_odds += if satisfied[c] { ratio } else { -ratio };
+                }
+                1.0 / (1.0 + (-log_odds).exp())
+            })
+            .collect();
+    }
+
+    probabilities
+}
+
+fn state_from_solution<S: RegisterState>(solution: &BitVector, degree: usize) -> S {
+    let mut state = S::zero(degree);
+    for i in (0..degree).filter(|&i| solution.get(i) == 1) {
+        state.flip(i);
+    }
+    state
+}
+
+// Solve the initial state from the bits that are most likely correct, taking equations in order of
+// reliability until they determine the state.
+fn solve_state<S: RegisterState>(lfsr: &LFSR<S>, bits: &[u8], probabilities: &[f64]) -> Option<S> {
+    let degree = lfsr.degree();
+    let equations = lfsr.output_equations(bits.len());
+    let mut order: Vec<usize> = (0..bits.len()).collect();
+    order.sort_by(|&a, &b| probabilities[b].total_cmp(&probabilities[a]));
+
+    let mut system = LinearSystem::new(degree);
+    for &i in &order {
+        system.add_equation(equations[i].clone(), bits[i]);
+        if system.equations() >= degree && system.rank() == degree {
+            break;
+        }
+    }
+
+    match system.solve() {
+        Solution::Unique(solution) => Some(state_from_solution(&solution, degree)),
+        _ => None,
+    }
+}
+
+pub fn fast_correlation_attack<S: RegisterState>(
+    lfsr: &LFSR<S>,
+    keystream: &[u8],
+    correlation: f64,
+    max_rounds: usize,
+) -> Option<FastCorrelationResult<S>> {
+    let exponents = lfsr.characteristic_polynomial().exponents();
+    // combined checks are weaker and far more numerous, so they are only added to a short keystream
+    let combine = relations(&exponents, keystream.len(), false).len() * exponents.len() < MIN_CHECKS_PER_BIT;
+    let checks = parity_checks(&exponents, keystream.len(), combine);
+    let mut bit_checks = vec![Vec::new(); keystream.len()];
+    for (c, check) in checks.iter().enumerate() {
+        for &i in check {
+            bit_checks[i].push(c);
+        }
+    }
+
+    let mut bits = keystream.to_vec();
+    let mut probabilities = vec![correlation; bits.len()];
+    let mut rounds = 0;
+
+    while rounds < max_rounds {
+        rounds += 1;
+        probabilities = posteriors(&bits, &checks, &bit_checks, correlation, 3);
+
+        let mut flipped = 0;
+        for (bit, p) in bits.iter_mut().zip(probabilities.iter_mut()) {
+            if *p < 0.5 {
+                *bit ^= 1;
+                *p = 1.0 - *p;
+                flipped += 1;
+            }
+        }
+
+        if flipped == 0 {
+            break;
+        }
+    }
+
+    let state = solve_state(lfsr, &bits, &probabilities)?;
+    let output = lfsr.clone().generate(state.clone(), keystream.len() as u64);
+    let agreement = output.iter().zip(keystream).filter(|(a, z)| a == z).count() as f64 / keystream.len() as f64;
+
+    // a wrong state agrees with about half of the keystream
+    if agreement < (0.5 + correlation) / 2.0 {
+        return None;
+    }
+
+    Some(FastCorrelationResult { state, agreement, rounds })
+}
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+
+use crate::alphabet::Alphabet;
+use crate::profile::LanguageProfile;
+use crate::text::preprocess_text;
+use crate::vigenere::decode;
+
+#[derive(Debug, Clone)]
+pub struct NgramScorer {
+    n: usize,
+    alphabet: Alphabet,
+    log_probabilities: HashMap<String, f64>,
+    floor: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ScoredCandidate {
+    pub key: String,
+    pub plaintext: String,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct KeySelection {
+    pub candidates: Vec<ScoredCandidate>,
+    // score lead of the best key over the runner-up, None with a single candidate
+    pub margin: Option<f64>,
+}
+
+impl NgramScorer {
+    // None for n = 0, which has no n-grams to score
+    pub fn from_corpus(text: &str, alphabet: &Alphabet, n: usize) -> Option<NgramScorer> {
+        if n == 0 {
+            return None;
+        }
+
+        let letters: Vec<char> = text.chars().filter(|&c| alphabet.contains(c)).collect();
+        let mut counts: HashMap<String, u64> = HashMap::new();
+        for window in letters.windows(n) {
+            *counts.entry(window.iter().collect()).or_insert(0) += 1;
+        }
+
+        let total = counts.values().sum::<u64>().max(1) as f64;
+        Some(NgramScorer {
+            n,
+            alphabet: alphabet.clone(),
+            log_probabilities: counts
+                .into_iter()
+                .map(|(ngram, count)| (ngram, (count as f64 / total).log10()))
+                .collect(),
+            floor: (0.01 / total).log10(),
+        })
+    }
+
+    pub fn from_corpus_file(input_file: &str, alphabet: &Alphabet, n: usize) -> io::Result<NgramScorer> {
+        let text = fs::read_to_string(input_file)?;
+        let processed_text = preprocess_text(&text, alphabet).unwrap_or_default();
+        NgramScorer::from_corpus(&processed_text, alphabet, n)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "n-gram size must be at least 1"))
+    }
+
+    // bigram scorer from the profile's bigram probabilities
+    pub fn from_profile(profile: &LanguageProfile) -> NgramScorer {
+        let smallest = profile.bigrams().values().copied().fold(1.0, f64::min);
+        NgramScorer {
+            n: 2,
+            alphabet: profile.alphabet().clone(),
+            log_probabilities: profile
+                .bigrams()
+                .iter()
+                .filter(|(_, &p)| p > 0.0)
+                .map(|(bigram, &p)| (bigram.clone(), p.log10()))
+                .collect(),
+            floor: (smallest / 100.0).log10(),
+        }
+    }
+
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    pub fn score(&self, text: &str) -> f64 {
+        let letters: Vec<char> = text.chars().filter(|&c| self.alphabet.contains(c)).collect();
+        let mut ngram = String::new();
+
+        letters
+            .windows(self.n)
+            .map(|window| {
+                ngram.clear();
+                ngram.extend(window);
+                self.log_probabilities.get(&ngram).copied().unwrap_or(self.floor)
+            })
+            .sum()
+    }
+
+    pub fn normalized_score(&self, text: &str) -> f64 {
+        let ngrams = text.chars().filter(|&c| self.alphabet.contains(c)).count().saturating_sub(self.n - 1);
+        if ngrams == 0 {
+            self.floor
+        } else {
+            self.score(text) / ngrams as f64
+        }
+    }
+}
+
+pub fn choose_best_key(text: &str, keys: &[String], alphabet: &Alphabet, scorer: &NgramScorer) -> KeySelection {
+    let mut candidates: Vec<ScoredCandidate> = Vec::new();
+
+    for key in keys {
+        if candidates.iter().any(|c| &c.key == key) {
+            continue;
+        }
+
+        let plaintext = decode(text, key, alphabet);
+        let score = scorer.normalized_score(&plaintext);
+        candidates.push(ScoredCandidate { key: key.clone(), plaintext, score });
+    }
+
+    candidates.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    let margin = match candidates.as_slice() {
+        [best, second, ..] => Some(best.score - second.score),
+        _ => None,
+    };
+
+    KeySelection { candidates, margin }
+}
@@ -0,0 +1,382 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use rayon::prelude::*;
+
+use crate::bitstream::{decode, truncate, BitFormat, BitOrder};
+use crate::boolean::BooleanFunction;
+use crate::errors::invalid_data;
+use crate::generators::{CombinerGenerator, KeystreamGenerator};
+use crate::geffe::{attack_parameters, find_candidates_parallel, forced_bits, recover_register_states, AttackParameters};
+use crate::lfsr::LFSR;
+use crate::polynomial::FeedbackPolynomial;
+
+// Registers are held in a u64 and searched exhaustively, which stops below 2^64 states.
+pub const MAX_DEGREE: usize = 63;
+
+// Inputs whose correlation with the keystream differs from 1/2 by at least this much are attacked
+// on their own.
+pub const MIN_CORRELATION_BIAS: f64 = 0.05;
+
+// Everything the correlation attack needs: the three feedback polynomials, the function combining
+// them (Geffe unless set), the observed keystream and the statistical parameters, with optional
+// manual overrides of N* and C per register.
+#[derive(Debug, Clone)]
+pub struct AttackConfig {
+    pub polynomials: [FeedbackPolynomial; 3],
+    pub function: BooleanFunction,
+    pub keystream: Vec<u8>,
+    pub alpha: f64,
+    pub beta: Option<f64>,
+    pub required_len: [Option<usize>; 3],
+    pub threshold: [Option<usize>; 3],
+}
+
+impl AttackConfig {
+    pub fn new(polynomials: [FeedbackPolynomial; 3], keystream: Vec<u8>) -> AttackConfig {
+        AttackConfig {
+            polynomials,
+            function: BooleanFunction::geffe(),
+            keystream,
+            alpha: 0.01,
+            beta: None,
+            required_len: [None; 3],
+            threshold: [None; 3],
+        }
+    }
+
+    pub fn degrees(&self) -> [usize; 3] {
+        [self.polynomials[0].degree(), self.polynomials[1].degree(), self.polynomials[2].degree()]
+    }
+
+    // Registers (numbered from 0) searched on their own: the two inputs of the combining function
+    // most correlated with its output by the Walsh spectrum, in register order. Fewer than two if the
+    // function leaks too little (LFSR1 and LFSR2 for Geffe, each agreeing with the keystream 3/4 of
+    // the time).
+    pub fn correlated_registers(&self) -> Vec<usize> {
+        let mut registers: Vec<usize> =
+            self.function.vulnerable_inputs(MIN_CORRELATION_BIAS).into_iter().take(2).map(|(register, _)| register).collect();
+        registers.sort_unstable();
+        registers
+    }
+
+    // N* and C for a correlated register, computed from its correlation with the combining function
+    // unless overridden. An input agreeing with the output less than half of the time is searched
+    // against the complemented keystream, so only the distance from 1/2 counts.
+    pub fn parameters(&self, register: usize) -> AttackParameters {
+        let correlation = self.function.input_correlation(register);
+        let computed = attack_parameters(self.polynomials[register].degree(), correlation.max(1.0 - correlation), self.alpha, self.beta);
+        AttackParameters {
+            required_len: self.required_len[register].unwrap_or(computed.required_len),
+            threshold: self.threshold[register].unwrap_or(computed.threshold),
+        }
+    }
+
+    pub fn is_overridden(&self, register: usize) -> bool {
+        self.required_len[register].is_some() || self.threshold[register].is_some()
+    }
+
+    // Reads a config file; a relative keystream path is resolved against the config's directory.
+    pub fn load(config_file: &str) -> io::Result<AttackConfig> {
+        let contents = fs::read_to_string(config_file)?;
+        let base = Path::new(config_file).parent().unwrap_or_else(|| Path::new("."));
+        AttackConfig::parse(&contents, |path| fs::read(base.join(path)))
+    }
+
+    // The format is a flat TOML subset: `key = value` lines, `#` comments, optionally quoted values.
+    //
+    //   lfsr1 = "x^25 + x^3 + 1"
+    //   lfsr2 = "x^26 + x^6 + x^2 + x + 1"
+    //   lfsr3 = "x^27 + x^5 + x^2 + x + 1"
+    //   function = "geffe"            # or majority, or the truth table "01010011" (entry x at position x)
+    //   keystream = "beta.txt"
+    //   keystream_format = "ascii"    # or binary / hex, see `bitstream`
+    //   bit_order = "msb"             # for binary keystreams
+    //   keystream_bits = 2047         # drops the padding of a binary or hex keystream
+    //   alpha = 0.01
+    //   lfsr1_threshold = 71
+    pub fn parse(contents: &str, read_keystream: impl Fn(&str) -> io::Result<Vec<u8>>) -> io::Result<AttackConfig> {
+        let mut polynomials: [Option<FeedbackPolynomial>; 3] = [None, None, None];
+        let mut function = None;
+        let mut keystream = None;
+        let mut format = BitFormat::Ascii;
+        let mut order = BitOrder::MsbFirst;
+        let mut keystream_bits = None;
+        let mut alpha = None;
+        let mut beta = None;
+        let mut required_len = [None; 3];
+        let mut threshold = [None; 3];
+
+        for (line_number, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let error = |message: &str| invalid_data(format!("line {}: {}", line_number + 1, message));
+            let (key, value) = line.split_once('=').ok_or_else(|| error("expected 'key = value'"))?;
+            let value = value.trim().trim_matches('"');
+            let number = |message: &str| value.parse::<usize>().map_err(|_| error(message));
+            let length = || match value.parse::<usize>() {
+                Ok(n) if n > 0 => Ok(n),
+                _ => Err(error("N* must be a positive length")),
+            };
+            let probability = |message: &str| match value.parse::<f64>() {
+                Ok(p) if p > 0.0 && p < 1.0 => Ok(p),
+                _ => Err(error(message)),
+            };
+
+            match key.trim() {
+                "lfsr1" | "lfsr2" | "lfsr3" => {
+                    let index = key.trim()[4..].parse::<usize>().unwrap() - 1;
+                    polynomials[index] = Some(FeedbackPolynomial::parse(value).ok_or_else(|| error("invalid polynomial"))?);
+                }
+                "function" => function = Some(parse_function(value).ok_or_else(|| error("invalid combining function"))?),
+                "keystream" => keystream = Some(read_keystream(value)?),
+                "keystream_format" => format = BitFormat::from_name(value).ok_or_else(|| error("unknown keystream format"))?,
+                "bit_order" => order = BitOrder::from_name(value).ok_or_else(|| error("unknown bit order"))?,
+                "keystream_bits" => keystream_bits = Some(number("invalid length")?),
+                "alpha" => alpha = Some(probability("alpha must lie in (0, 1)")?),
+                "beta" => beta = Some(probability("beta must lie in (0, 1)")?),
+                "lfsr1_required_len" => required_len[0] = Some(length()?),
+                "lfsr2_required_len" => required_len[1] = Some(length()?),
+                "lfsr3_required_len" => required_len[2] = Some(length()?),
+                "lfsr1_threshold" => threshold[0] = Some(number("invalid threshold")?),
+                "lfsr2_threshold" => threshold[1] = Some(number("invalid threshold")?),
+                "lfsr3_threshold" => threshold[2] = Some(number("invalid threshold")?),
+                _ => return Err(error("unknown key")),
+            }
+        }
+
+        let missing = |name: &str| invalid_data(format!("missing '{}'", name));
+        let keystream = decode(&keystream.ok_or_else(|| missing("keystream"))?, format, order)
+            .and_then(|bits| match keystream_bits {
+                Some(length) => truncate(bits, length),
+                None => Ok(bits),
+            })
+            .map_err(|e| invalid_data(format!("keystream: {}", e)))?;
+        let [p1, p2, p3] = polynomials;
+        let mut config = AttackConfig::new(
+            [p1.ok_or_else(|| missing("lfsr1"))?, p2.ok_or_else(|| missing("lfsr2"))?, p3.ok_or_else(|| missing("lfsr3"))?],
+            keystream,
+        );
+        config.function = function.unwrap_or(config.function);
+        config.alpha = alpha.unwrap_or(config.alpha);
+        config.beta = beta;
+        config.required_len = required_len;
+        config.threshold = threshold;
+
+        Ok(config)
+    }
+}
+
+// A combining function of three inputs by name (geffe, majority) or by its truth table, entry x at
+// position x.
+pub fn parse_function(text: &str) -> Option<BooleanFunction> {
+    match text {
+        "geffe" => Some(BooleanFunction::geffe()),
+        "majority" => Some(BooleanFunction::majority(3)),
+        table => {
+            let truth_table = table.chars().map(|c| c.to_digit(2).map(|bit| bit as u8)).collect::<Option<Vec<u8>>>()?;
+            BooleanFunction::new(3, truth_table)
+        }
+    }
+}
+
+// A state of each of the two correlated registers (in the order of `correlated_registers`) that
+// both passed the correlation test, with their discrepancies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CandidatePair {
+    pub states: [u64; 2],
+    pub discrepancies: [usize; 2],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GeffeKey {
+    pub lfsr1: u64,
+    pub lfsr2: u64,
+    pub lfsr3: u64,
+}
+
+// Every key that reproduces the keystream, in the order of the candidate pairs they came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recovery {
+    pub keys: Vec<GeffeKey>,
+    pub candidate_pairs: Vec<CandidatePair>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttackError {
+    // N* of a register (numbered from 1) exceeds the keystream length
+    KeystreamTooShort { register: usize, required_len: usize, available: usize },
+    // a register (numbered from 1) is too wide to be held or searched
+    RegisterTooWide { register: usize, degree: usize },
+    // the combining function does not take three inputs or leaks fewer than two of them
+    UnsupportedFunction { correlated: usize },
+    // no state of a correlated register (numbered from 1) passed the correlation test
+    NoCandidates { register: usize },
+    // no state of the remaining register (numbered from 1) fits any candidate pair
+    NoConsistentState { register: usize, candidate_pairs: Vec<CandidatePair> },
+    // the keystream leaves too many state bits of the remaining register (numbered from 1)
+    // undetermined to solve for or enumerate
+    Underdetermined { register: usize, free_bits: usize, candidate_pairs: Vec<CandidatePair> },
+    VerificationMismatch { key: GeffeKey, candidate_pairs: Vec<CandidatePair> },
+}
+
+impl fmt::Display for AttackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttackError::KeystreamTooShort { register, required_len, available } => write!(
+                f,
+                "LFSR{} needs N* = {} keystream bits, only {} are available",
+                register, required_len, available
+            ),
+            AttackError::RegisterTooWide { register, degree } => write!(
+                f,
+                "LFSR{} has degree {}, the attack handles registers of degree at most {}",
+                register, degree, MAX_DEGREE
+            ),
+            AttackError::UnsupportedFunction { correlated } => write!(
+                f,
+                "the combining function must take 3 inputs, 2 of them correlated with its output ({} are)",
+                correlated
+            ),
+            AttackError::NoCandidates { register } => write!(f, "no LFSR{} state passed the correlation test", register),
+            AttackError::NoConsistentState { register, candidate_pairs } => write!(
+                f,
+                "no LFSR{} state reproduces the keystream for any of {} candidate pairs",
+                register,
+                candidate_pairs.len()
+            ),
+            AttackError::Underdetermined { register, free_bits, .. } => write!(
+                f,
+                "the keystream leaves {} LFSR{} state bits free; a longer keystream is needed",
+                free_bits, register
+            ),
+            AttackError::VerificationMismatch { key, .. } => write!(
+                f,
+                "key ({}, {}, {}) does not reproduce the keystream",
+                key.lfsr1, key.lfsr2, key.lfsr3
+            ),
+        }
+    }
+}
+
+impl Error for AttackError {}
+
+// Every combination of the surviving states, most correlated (smallest total discrepancy) first
+// (a state met twice on the search walk is only paired once).
+pub fn candidate_pairs(candidates1: &[(u64, usize)], candidates2: &[(u64, usize)]) -> Vec<CandidatePair> {
+    let unique = |candidates: &[(u64, usize)]| {
+        let mut candidates = candidates.to_vec();
+        candidates.sort_unstable();
+        candidates.dedup_by_key(|&mut (state, _)| state);
+        candidates
+    };
+    let (candidates1, candidates2) = (unique(candidates1), unique(candidates2));
+
+    let mut pairs: Vec<CandidatePair> = candidates1
+        .iter()
+        .flat_map(|&(state1, discrepancy1)| {
+            candidates2.iter().map(move |&(state2, discrepancy2)| CandidatePair {
+                states: [state1, state2],
+                discrepancies: [discrepancy1, discrepancy2],
+            })
+        })
+        .collect();
+
+    pairs.sort_by_key(|pair| pair.discrepancies[0] + pair.discrepancies[1]);
+    pairs
+}
+
+// The two correlated registers are searched one by one, then the remaining one is solved for from
+// the positions where the combining function depends on it (for Geffe: LFSR1 and LFSR2 are
+// searched, and the selector LFSR3 is solved from the positions where they differ).
+pub fn attack(config: &AttackConfig, progress: Option<&(dyn Fn(u64, u64) + Sync)>) -> Result<Recovery, AttackError> {
+    let degrees = config.degrees();
+    let target_seq = &config.keystream;
+    let n = target_seq.len();
+
+    if let Some(register) = (0..3).find(|&register| degrees[register] > MAX_DEGREE) {
+        return Err(AttackError::RegisterTooWide { register: register + 1, degree: degrees[register] });
+    }
+
+    let correlated = config.correlated_registers();
+    if config.function.variables() != 3 || correlated.len() < 2 {
+        return Err(AttackError::UnsupportedFunction { correlated: correlated.len() });
+    }
+    let remaining = (0..3).find(|register| !correlated.contains(register)).unwrap();
+
+    for &register in &correlated {
+        let parameters = config.parameters(register);
+        if parameters.required_len > n {
+            return Err(AttackError::KeystreamTooShort { register: register + 1, required_len: parameters.required_len, available: n });
+        }
+    }
+
+    let mut lfsrs = config.polynomials.clone().map(|p| LFSR::<u64>::from_polynomial(&p));
+
+    let mut candidates: [Vec<(u64, usize)>; 2] = Default::default();
+    for (k, &register) in correlated.iter().enumerate() {
+        let parameters = config.parameters(register);
+        // an input that mostly disagrees with the output is matched against the complement
+        let flip = (config.function.input_correlation(register) < 0.5) as u8;
+        let target: Vec<u8> = target_seq.iter().map(|&z| z ^ flip).collect();
+        candidates[k] = find_candidates_parallel(&lfsrs[register], &target, parameters.required_len, parameters.threshold, degrees[register], progress);
+        if candidates[k].is_empty() {
+            return Err(AttackError::NoCandidates { register: register + 1 });
+        }
+    }
+
+    let seqs: Vec<HashMap<u64, Vec<u8>>> = correlated
+        .iter()
+        .zip(&candidates)
+        .map(|(&register, candidates)| candidates.iter().map(|&(state, _)| (state, lfsrs[register].generate(state, n as u64))).collect())
+        .collect();
+
+    let candidate_pairs = candidate_pairs(&candidates[0], &candidates[1]);
+    let solved: Result<Vec<Vec<GeffeKey>>, usize> = candidate_pairs
+        .par_iter()
+        .map(|pair| {
+            let others = [seqs[0][&pair.states[0]].as_slice(), seqs[1][&pair.states[1]].as_slice()];
+            let states = match forced_bits(&config.function, remaining, &others, target_seq) {
+                Some(forced) => recover_register_states(&lfsrs[remaining], &forced)?,
+                None => Vec::new(),
+            };
+            Ok(states
+                .into_iter()
+                .map(|state| {
+                    let mut key = [0u64; 3];
+                    key[correlated[0]] = pair.states[0];
+                    key[correlated[1]] = pair.states[1];
+                    key[remaining] = state;
+                    GeffeKey { lfsr1: key[0], lfsr2: key[1], lfsr3: key[2] }
+                })
+                .collect())
+        })
+        .collect();
+    let keys: Vec<GeffeKey> = match solved {
+        Ok(keys) => keys.into_iter().flatten().collect(),
+        Err(free_bits) => return Err(AttackError::Underdetermined { register: remaining + 1, free_bits, candidate_pairs }),
+    };
+
+    if keys.is_empty() {
+        return Err(AttackError::NoConsistentState { register: remaining + 1, candidate_pairs });
+    }
+
+    for &key in &keys {
+        for (lfsr, state) in lfsrs.iter_mut().zip([key.lfsr1, key.lfsr2, key.lfsr3]) {
+            lfsr.set_state(state);
+        }
+        let mut generator = CombinerGenerator::new(lfsrs.to_vec(), config.function.clone());
+        if &generator.keystream(n) != target_seq {
+            return Err(AttackError::VerificationMismatch { key, candidate_pairs });
+        }
+    }
+
+    Ok(Recovery { keys, candidate_pairs })
+}
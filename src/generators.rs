@@ -0,0 +1,160 @@
+use crate::boolean::BooleanFunction;
+use crate::lfsr::{RegisterState, LFSR};
+
+// A source of keystream bits. Generators are built from registers whose states are already set
+// (see `LFSR::set_state`) and produce one bit per call.
+pub trait KeystreamGenerator {
+    fn next_bit(&mut self) -> u8;
+
+    fn keystream(&mut self, length: usize) -> Vec<u8> {
+        (0..length).map(|_| self.next_bit()).collect()
+    }
+}
+
+// Majority of an odd number of registers (3 or 5 in practice), all clocked every step.
+pub struct ThresholdGenerator<S: RegisterState = u64> {
+    registers: Vec<LFSR<S>>,
+}
+
+impl<S: RegisterState> ThresholdGenerator<S> {
+    pub fn new(registers: Vec<LFSR<S>>) -> ThresholdGenerator<S> {
+        assert!(registers.len() % 2 == 1, "the threshold generator needs an odd number of registers");
+        ThresholdGenerator { registers }
+    }
+}
+
+impl<S: RegisterState> KeystreamGenerator for ThresholdGenerator<S> {
+    fn next_bit(&mut self) -> u8 {
+        let ones = self.registers.iter_mut().map(|r| r.clock() as usize).sum::<usize>();
+        (2 * ones > self.registers.len()) as u8
+    }
+}
+
+// Both registers are clocked together; the source bit is output only when the selector bit is 1.
+pub struct ShrinkingGenerator<S: RegisterState = u64> {
+    selector: LFSR<S>,
+    source: LFSR<S>,
+}
+
+impl<S: RegisterState> ShrinkingGenerator<S> {
+    pub fn new(selector: LFSR<S>, source: LFSR<S>) -> ShrinkingGenerator<S> {
+        ShrinkingGenerator { selector, source }
+    }
+}
+
+impl<S: RegisterState> KeystreamGenerator for ShrinkingGenerator<S> {
+    fn next_bit(&mut self) -> u8 {
+        loop {
+            let select = self.selector.clock();
+            let bit = self.source.clock();
+            if select == 1 {
+                return bit;
+            }
+        }
+    }
+}
+
+// One register read in pairs (a, b): b is output when a is 1, the pair is dropped otherwise.
+pub struct SelfShrinkingGenerator<S: RegisterState = u64> {
+    register: LFSR<S>,
+}
+
+impl<S: RegisterState> SelfShrinkingGenerator<S> {
+    pub fn new(register: LFSR<S>) -> SelfShrinkingGenerator<S> {
+        SelfShrinkingGenerator { register }
+    }
+}
+
+impl<S: RegisterState> KeystreamGenerator for SelfShrinkingGenerator<S> {
+    fn next_bit(&mut self) -> u8 {
+        loop {
+            let select = self.register.clock();
+            let bit = self.register.clock();
+            if select == 1 {
+                return bit;
+            }
+        }
+    }
+}
+
+// The control register decides which of the two others is clocked (lfsr1 on 1, lfsr2 on 0); the
+// other repeats its previous output bit, initially 0. The output is the XOR of both.
+pub struct AlternatingStepGenerator<S: RegisterState = u64> {
+    control: LFSR<S>,
+    lfsr1: LFSR<S>,
+    lfsr2: LFSR<S>,
+    last1: u8,
+    last2: u8,
+}
+
+impl<S: RegisterState> AlternatingStepGenerator<S> {
+    pub fn new(control: LFSR<S>, lfsr1: LFSR<S>, lfsr2: LFSR<S>) -> AlternatingStepGenerator<S> {
+        AlternatingStepGenerator { control, lfsr1, lfsr2, last1: 0, last2: 0 }
+    }
+}
+
+impl<S: RegisterState> KeystreamGenerator for AlternatingStepGenerator<S> {
+    fn next_bit(&mut self) -> u8 {
+        if self.control.clock() == 1 {
+            self.last1 = self.lfsr1.clock();
+        } else {
+            self.last2 = self.lfsr2.clock();
+        }
+        self.last1 ^ self.last2
+    }
+}
+
+// A5/1-style stop-and-go clocking: every register has a clocking bit, and only the registers whose
+// clocking bit agrees with the majority of them are clocked. The output is the XOR of the lowest
+// state bits after clocking.
+pub struct MajorityClockedGenerator<S: RegisterState = u64> {
+    registers: Vec<(LFSR<S>, usize)>,
+}
+
+impl<S: RegisterState> MajorityClockedGenerator<S> {
+    // each register comes with the position of its clocking bit in the state
+    pub fn new(registers: Vec<(LFSR<S>, usize)>) -> MajorityClockedGenerator<S> {
+        assert!(registers.len() % 2 == 1, "majority clocking needs an odd number of registers");
+        assert!(registers.iter().all(|(r, position)| *position < r.degree()), "clocking bit outside the register");
+        MajorityClockedGenerator { registers }
+    }
+}
+
+impl<S: RegisterState> KeystreamGenerator for MajorityClockedGenerator<S> {
+    fn next_bit(&mut self) -> u8 {
+        let bits: Vec<u8> = self.registers.iter().map(|(r, position)| r.state().bit(*position)).collect();
+        let majority = (2 * bits.iter().map(|&b| b as usize).sum::<usize>() > bits.len()) as u8;
+
+        for ((register, _), bit) in self.registers.iter_mut().zip(bits) {
+            if bit == majority {
+                register.clock();
+            }
+        }
+
+        self.registers.iter().fold(0, |acc, (r, _)| acc ^ r.state().lowest_bit())
+    }
+}
+
+// Any Boolean function of the register outputs, register i feeding variable i.
+pub struct CombinerGenerator<S: RegisterState = u64> {
+    registers: Vec<LFSR<S>>,
+    function: BooleanFunction,
+}
+
+impl<S: RegisterState> CombinerGenerator<S> {
+    pub fn new(registers: Vec<LFSR<S>>, function: BooleanFunction) -> CombinerGenerator<S> {
+        assert_eq!(registers.len(), function.variables(), "one register is needed per variable");
+        CombinerGenerator { registers, function }
+    }
+
+    pub fn function(&self) -> &BooleanFunction {
+        &self.function
+    }
+}
+
+impl<S: RegisterState> KeystreamGenerator for CombinerGenerator<S> {
+    fn next_bit(&mut self) -> u8 {
+        let inputs: Vec<u8> = self.registers.iter_mut().map(|r| r.clock()).collect();
+        self.function.evaluate(&inputs)
+    }
+}
@@ -0,0 +1,410 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::modarith::factorize;
+use crate::polynomial::FeedbackPolynomial;
+use crate::rng::XorShift64;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Gf2Polynomial {
+    words: Vec<u64>,
+}
+
+impl Gf2Polynomial {
+    pub fn zero() -> Gf2Polynomial {
+        Gf2Polynomial { words: Vec::new() }
+    }
+
+    pub fn one() -> Gf2Polynomial {
+        Gf2Polynomial::from_exponents(&[0])
+    }
+
+    pub fn x() -> Gf2Polynomial {
+        Gf2Polynomial::from_exponents(&[1])
+    }
+
+    pub fn from_exponents(exponents: &[usize]) -> Gf2Polynomial {
+        let mut polynomial = Gf2Polynomial::zero();
+        for &e in exponents {
+            polynomial.flip(e);
+        }
+        polynomial
+    }
+
+    pub fn from_feedback(polynomial: &FeedbackPolynomial) -> Gf2Polynomial {
+        Gf2Polynomial::from_exponents(polynomial.exponents())
+    }
+
+    fn normalize(mut self) -> Gf2Polynomial {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+        self
+    }
+
+    fn flip(&mut self, exponent: usize) {
+        if self.words.len() <= exponent / 64 {
+            self.words.resize(exponent / 64 + 1, 0);
+        }
+        self.words[exponent / 64] ^= 1 << (exponent % 64);
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+
+    pub fn coefficient(&self, exponent: usize) -> u8 {
+        self.words.get(exponent / 64).map_or(0, |w| (w >> (exponent % 64) & 1) as u8)
+    }
+
+    pub fn degree(&self) -> Option<usize> {
+        let last = *self.words.last()?;
+        Some((self.words.len() - 1) * 64 + 63 - last.leading_zeros() as usize)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.words == [1]
+    }
+
+    pub fn exponents(&self) -> Vec<usize> {
+        (0..=self.degree().unwrap_or(0)).rev().filter(|&e| self.coefficient(e) == 1).collect()
+    }
+
+    pub fn add(&self, other: &Gf2Polynomial) -> Gf2Polynomial {
+        let (longer, shorter) = if self.words.len() >= other.words.len() { (self, other) } else { (other, self) };
+        let mut words = longer.words.clone();
+        for (w, o) in words.iter_mut().zip(shorter.words.iter()) {
+            *w ^= o;
+        }
+        Gf2Polynomial { words }.normalize()
+    }
+
+    pub fn shift_left(&self, k: usize) -> Gf2Polynomial {
+        if self.is_zero() {
+            return Gf2Polynomial::zero();
+        }
+
+        let (word_shift, bit_shift) = (k / 64, k % 64);
+        let mut words = vec![0u64; self.words.len() + word_shift + 1];
+        for (i, &w) in self.words.iter().enumerate() {
+            words[i + word_shift] ^= w << bit_shift;
+            if bit_shift > 0 {
+                words[i + word_shift + 1] ^= w >> (64 - bit_shift);
+            }
+        }
+        Gf2Polynomial { words }.normalize()
+    }
+
+    pub fn mul(&self, other: &Gf2Polynomial) -> Gf2Polynomial {
+        let mut result = Gf2Polynomial::zero();
+        for e in self.exponents() {
+            result = result.add(&other.shift_left(e));
+        }
+        result
+    }
+
+    pub fn div_rem(&self, divisor: &Gf2Polynomial) -> (Gf2Polynomial, Gf2Polynomial) {
+        let divisor_degree = divisor.degree().expect("division by the zero polynomial");
+        let mut quotient = Gf2Polynomial::zero();
+        let mut remainder = self.clone();
+
+        while let Some(degree) = remainder.degree() {
+            if degree < divisor_degree {
+                break;
+            }
+            let shift = degree - divisor_degree;
+            remainder = remainder.add(&divisor.shift_left(shift));
+            quotient.flip(shift);
+        }
+
+        (quotient, remainder)
+    }
+
+    pub fn rem(&self, modulus: &Gf2Polynomial) -> Gf2Polynomial {
+        self.div_rem(modulus).1
+    }
+
+    pub fn gcd(&self, other: &Gf2Polynomial) -> Gf2Polynomial {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let r = a.rem(&b);
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    pub fn mul_mod(&self, other: &Gf2Polynomial, modulus: &Gf2Polynomial) -> Gf2Polynomial {
+        self.mul(other).rem(modulus)
+    }
+
+    pub fn pow_mod(&self, mut exponent: u128, modulus: &Gf2Polynomial) -> Gf2Polynomial {
+        let mut result = Gf2Polynomial::one().rem(modulus);
+        let mut base = self.rem(modulus);
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul_mod(&base, modulus);
+            }
+            base = base.mul_mod(&base, modulus);
+            exponent >>= 1;
+        }
+
+        result
+    }
+
+    pub fn derivative(&self) -> Gf2Polynomial {
+        let exponents: Vec<usize> = self.exponents().into_iter().filter(|e| e % 2 == 1).map(|e| e - 1).collect();
+        Gf2Polynomial::from_exponents(&exponents)
+    }
+
+    // only valid for perfect squares, where every exponent is even
+    fn sqrt(&self) -> Gf2Polynomial {
+        let exponents: Vec<usize> = self.exponents().into_iter().map(|e| e / 2).collect();
+        Gf2Polynomial::from_exponents(&exponents)
+    }
+
+    fn x_power_of_two(k: usize, modulus: &Gf2Polynomial) -> Gf2Polynomial {
+        let mut result = Gf2Polynomial::x().rem(modulus);
+        for _ in 0..k {
+            result = result.mul_mod(&result, modulus);
+        }
+        result
+    }
+}
+
+impl fmt::Display for Gf2Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        let terms: Vec<String> = self
+            .exponents()
+            .into_iter()
+            .map(|e| match e {
+                0 => "1".to_string(),
+                1 => "x".to_string(),
+                e => format!("x^{}", e),
+            })
+            .collect();
+        write!(f, "{}", terms.join(" + "))
+    }
+}
+
+fn distinct_prime_factors(n: usize) -> Vec<usize> {
+    let mut factors: Vec<usize> = factorize(n as u64).into_iter().map(|p| p as usize).collect();
+    factors.dedup();
+    factors
+}
+
+pub fn is_irreducible(f: &Gf2Polynomial) -> bool {
+    let n = match f.degree() {
+        Some(0) | None => return false,
+        Some(n) => n,
+    };
+
+    let x = Gf2Polynomial::x().rem(f);
+    if Gf2Polynomial::x_power_of_two(n, f) != x {
+        return false;
+    }
+
+    distinct_prime_factors(n)
+        .into_iter()
+        .all(|q| Gf2Polynomial::x_power_of_two(n / q, f).add(&x).gcd(f).is_one())
+}
+
+pub fn mersenne_prime_factors(n: usize) -> Option<Vec<u128>> {
+    if n == 0 || n > 64 {
+        return None;
+    }
+
+    let mut factors: Vec<u128> = factorize(((1u128 << n) - 1) as u64).into_iter().map(|p| p as u128).collect();
+    factors.dedup();
+    Some(factors)
+}
+
+// `prime_factors` are the prime factors of 2^n - 1, with or without repeats. None if they do not
+// multiply back to 2^n - 1 (or n > 128), since a missing factor could let a non-primitive f through.
+pub fn is_primitive_with_factors(f: &Gf2Polynomial, prime_factors: &[u128]) -> Option<bool> {
+    let n = match f.degree()? {
+        n if n > 128 => return None,
+        n => n,
+    };
+    if n == 0 || f.coefficient(0) == 0 || !is_irreducible(f) {
+        return Some(false);
+    }
+
+    let order = u128::MAX >> (128 - n);
+    let mut primes = prime_factors.to_vec();
+    primes.sort_unstable();
+    primes.dedup();
+    let mut rest = order;
+    for &p in &primes {
+        if p < 2 || !rest.is_multiple_of(p) {
+            return None;
+        }
+        while rest.is_multiple_of(p) {
+            rest /= p;
+        }
+    }
+    if rest != 1 {
+        return None;
+    }
+
+    let x = Gf2Polynomial::x();
+    Some(primes.iter().all(|&p| !x.pow_mod(order / p, f).is_one()))
+}
+
+pub fn is_primitive(f: &Gf2Polynomial) -> Option<bool> {
+    let factors = mersenne_prime_factors(f.degree()?)?;
+    is_primitive_with_factors(f, &factors)
+}
+
+pub fn squarefree_factorization(f: &Gf2Polynomial) -> Vec<(Gf2Polynomial, usize)> {
+    let mut factors = Vec::new();
+    if f.degree().unwrap_or(0) == 0 {
+        return factors;
+    }
+
+    let mut c = f.gcd(&f.derivative());
+    let mut w = f.div_rem(&c).0;
+    let mut i = 1;
+
+    while !w.is_one() {
+        let y = w.gcd(&c);
+        let factor = w.div_rem(&y).0;
+        if !factor.is_one() {
+            factors.push((factor, i));
+        }
+        w = y.clone();
+        c = c.div_rem(&y).0;
+        i += 1;
+    }
+
+    if !c.is_one() {
+        for (g, multiplicity) in squarefree_factorization(&c.sqrt()) {
+            factors.push((g, multiplicity * 2));
+        }
+    }
+
+    factors
+}
+
+pub fn distinct_degree_factorization(f: &Gf2Polynomial) -> Vec<(Gf2Polynomial, usize)> {
+    let mut factors = Vec::new();
+    let mut remaining = f.clone();
+    let mut h = Gf2Polynomial::x().rem(f);
+    let mut i = 1;
+
+    while remaining.degree().unwrap_or(0) >= 2 * i {
+        h = h.mul_mod(&h, &remaining);
+        let g = h.add(&Gf2Polynomial::x()).gcd(&remaining);
+        if !g.is_one() {
+            factors.push((g.clone(), i));
+            remaining = remaining.div_rem(&g).0;
+            h = h.rem(&remaining);
+        }
+        i += 1;
+    }
+
+    if let Some(degree) = remaining.degree().filter(|&d| d > 0) {
+        factors.push((remaining, degree));
+    }
+
+    factors
+}
+
+pub fn equal_degree_factorization(f: &Gf2Polynomial, d: usize) -> Vec<Gf2Polynomial> {
+    let n = f.degree().unwrap_or(0);
+    if n <= d {
+        return vec![f.clone()];
+    }
+
+    let mut rng = XorShift64::new(0x9e37_79b9_7f4a_7c15 ^ n as u64);
+    loop {
+        let exponents: Vec<usize> = (0..n).filter(|_| rng.next_u64() & 1 == 1).collect();
+        let a = Gf2Polynomial::from_exponents(&exponents);
+
+        let mut trace = a.clone();
+        let mut power = a;
+        for _ in 1..d {
+            power = power.mul_mod(&power, f);
+            trace = trace.add(&power);
+        }
+
+        let g = trace.gcd(f);
+        if let Some(degree) = g.degree() {
+            if degree > 0 && degree < n {
+                let mut factors = equal_degree_factorization(&g, d);
+                factors.extend(equal_degree_factorization(&f.div_rem(&g).0, d));
+                return factors;
+            }
+        }
+    }
+}
+
+pub fn factor(f: &Gf2Polynomial) -> Vec<(Gf2Polynomial, usize)> {
+    let mut factors = Vec::new();
+
+    for (squarefree, multiplicity) in squarefree_factorization(f) {
+        for (product, degree) in distinct_degree_factorization(&squarefree) {
+            for irreducible in equal_degree_factorization(&product, degree) {
+                factors.push((irreducible, multiplicity));
+            }
+        }
+    }
+
+    factors.sort_by(|a, b| match a.0.degree().cmp(&b.0.degree()) {
+        Ordering::Equal => a.0.exponents().cmp(&b.0.exponents()),
+        ordering => ordering,
+    });
+    factors
+}
+
+fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+pub fn order_of_x(irreducible: &Gf2Polynomial) -> Option<u128> {
+    let d = irreducible.degree()?;
+    if irreducible.coefficient(0) == 0 {
+        return None;
+    }
+
+    let mut order = (1u128 << d) - 1;
+    for p in mersenne_prime_factors(d)? {
+        while order.is_multiple_of(p) && Gf2Polynomial::x().pow_mod(order / p, irreducible).is_one() {
+            order /= p;
+        }
+    }
+
+    Some(order)
+}
+
+pub fn period(f: &Gf2Polynomial) -> Option<u128> {
+    if f.coefficient(0) == 0 || f.degree()? > 64 {
+        return None;
+    }
+
+    let mut period = 1u128;
+    let mut max_multiplicity = 1;
+    for (irreducible, multiplicity) in factor(f) {
+        let order = order_of_x(&irreducible)?;
+        period = period / gcd_u128(period, order) * order;
+        max_multiplicity = max_multiplicity.max(multiplicity);
+    }
+
+    let mut power_of_two = 1u128;
+    while power_of_two < max_multiplicity as u128 {
+        power_of_two *= 2;
+    }
+
+    Some(period * power_of_two)
+}
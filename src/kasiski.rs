@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use crate::modarith::factorize;
+use crate::profile::LanguageProfile;
+use crate::vigenere::average_coincidence;
+
+#[derive(Debug, Clone)]
+pub struct KasiskiScore {
+    pub length: usize,
+    pub divisible: usize,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct KeyLengthEvidence {
+    pub length: usize,
+    pub kasiski_score: f64,
+    pub coincidence: f64,
+    pub coincidence_score: f64,
+    pub combined_score: f64,
+}
+
+pub fn find_repeated_ngrams(text: &str, n: usize) -> HashMap<String, Vec<usize>> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut positions: HashMap<String, Vec<usize>> = HashMap::new();
+
+    for (position, window) in chars.windows(n).enumerate() {
+        positions.entry(window.iter().collect()).or_default().push(position);
+    }
+
+    positions.retain(|_, occurrences| occurrences.len() > 1);
+    positions
+}
+
+pub fn repeat_distances(text: &str, min_n: usize, max_n: usize) -> Vec<usize> {
+    let mut distances = Vec::new();
+
+    for n in min_n..=max_n {
+        for occurrences in find_repeated_ngrams(text, n).values() {
+            distances.extend(occurrences.windows(2).map(|pair| pair[1] - pair[0]));
+        }
+    }
+
+    distances
+}
+
+// All divisors of n, built from its prime factorization.
+fn divisors(n: usize) -> Vec<usize> {
+    let mut divisors = vec![1];
+    let factors = factorize(n as u64);
+    for group in factors.chunk_by(|a, b| a == b) {
+        let p = group[0] as usize;
+        let mut powers = Vec::with_capacity(divisors.len() * group.len());
+        for &d in &divisors {
+            let mut power = d;
+            for _ in group {
+                power *= p;
+                powers.push(power);
+            }
+        }
+        divisors.extend(powers);
+    }
+    divisors
+}
+
+// Ties go to the shorter length, as in the other key-length rankings.
+pub fn rank_key_lengths(distances: &[usize], min_r: usize, max_r: usize) -> Vec<KasiskiScore> {
+    let mut divisor_counts: HashMap<usize, usize> = HashMap::new();
+    for &distance in distances.iter().filter(|&&d| d > 0) {
+        for divisor in divisors(distance) {
+            *divisor_counts.entry(divisor).or_insert(0) += 1;
+        }
+    }
+
+    let mut scores: Vec<KasiskiScore> = (min_r.max(1)..=max_r)
+        .map(|r| {
+            let divisible = divisor_counts.get(&r).copied().unwrap_or(0);
+            // a random distance is divisible by r with probability 1/r, so only the excess counts
+            // (every distance is divisible by 1, which therefore carries no evidence)
+            let score = if distances.is_empty() || r == 1 {
+                0.0
+            } else {
+                let fraction = divisible as f64 / distances.len() as f64;
+                let chance = 1.0 / r as f64;
+                ((fraction - chance) / (1.0 - chance)).max(0.0)
+            };
+            KasiskiScore { length: r, divisible, score }
+        })
+        .collect();
+
+    scores.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal).then(a.length.cmp(&b.length)));
+    scores
+}
+
+pub fn kasiski(text: &str, min_r: usize, max_r: usize) -> Vec<KasiskiScore> {
+    rank_key_lengths(&repeat_distances(text, 3, 5), min_r, max_r)
+}
+
+pub fn combined_key_length_ranking(text: &str, profile: &LanguageProfile, min_r: usize, max_r: usize) -> Vec<KeyLengthEvidence> {
+    let kasiski_scores = kasiski(text, min_r, max_r);
+    let expected_i = profile.expected_coincidence();
+    let random_i = 1.0 / profile.alphabet().size() as f64;
+
+    let mut evidence: Vec<KeyLengthEvidence> = (min_r.max(1)..=max_r)
+        .map(|r| {
+            let kasiski_score = kasiski_scores.iter().find(|s| s.length == r).map_or(0.0, |s| s.score);
+            let coincidence = average_coincidence(text, r, profile.alphabet());
+            let coincidence_score = (1.0 - (expected_i - coincidence).abs() / (expected_i - random_i).abs()).clamp(0.0, 1.0);
+            KeyLengthEvidence {
+                length: r,
+                kasiski_score,
+                coincidence,
+                coincidence_score,
+                combined_score: (kasiski_score + coincidence_score) / 2.0,
+            }
+        })
+        .collect();
+
+    evidence.sort_by(|a, b| b.combined_score.partial_cmp(&a.combined_score).unwrap_or(std::cmp::Ordering::Equal).then(a.length.cmp(&b.length)));
+    evidence
+}
@@ -0,0 +1,292 @@
+use std::collections::VecDeque;
+
+use crate::bits::BitVector;
+use crate::gf2::Gf2Polynomial;
+use crate::polynomial::FeedbackPolynomial;
+
+pub trait RegisterState: Clone {
+    fn zero(degree: usize) -> Self;
+    fn from_u64(value: u64, degree: usize) -> Self;
+    fn lowest_bit(&self) -> u8;
+    fn and_parity(&self, taps: &Self) -> u8;
+    fn shift_in(&mut self, bit: u8, position: usize);
+    fn bit(&self, position: usize) -> u8;
+    fn flip(&mut self, position: usize);
+    fn xor_with(&mut self, other: &Self);
+}
+
+macro_rules! impl_register_state {
+    ($($t:ty),*) => {
+        $(
+            impl RegisterState for $t {
+                fn zero(_degree: usize) -> Self {
+                    0
+                }
+
+                fn from_u64(value: u64, _degree: usize) -> Self {
+                    value as $t
+                }
+
+                fn lowest_bit(&self) -> u8 {
+                    (self & 1) as u8
+                }
+
+                fn and_parity(&self, taps: &Self) -> u8 {
+                    ((self & taps).count_ones() & 1) as u8
+                }
+
+                fn shift_in(&mut self, bit: u8, position: usize) {
+                    *self = (*self >> 1) ^ ((bit as $t) << position);
+                }
+
+                fn bit(&self, position: usize) -> u8 {
+                    ((self >> position) & 1) as u8
+                }
+
+                fn flip(&mut self, position: usize) {
+                    *self ^= 1 << position;
+                }
+
+                fn xor_with(&mut self, other: &Self) {
+                    *self ^= other;
+                }
+            }
+        )*
+    };
+}
+
+impl_register_state!(u32, u64, u128);
+
+impl RegisterState for BitVector {
+    fn zero(degree: usize) -> Self {
+        BitVector::new(degree)
+    }
+
+    fn from_u64(value: u64, degree: usize) -> Self {
+        BitVector::from_u64(value, degree)
+    }
+
+    fn lowest_bit(&self) -> u8 {
+        self.get(0)
+    }
+
+    fn and_parity(&self, taps: &Self) -> u8 {
+        BitVector::and_parity(self, taps)
+    }
+
+    fn shift_in(&mut self, bit: u8, position: usize) {
+        self.shift_right_one();
+        if bit == 1 {
+            self.toggle(position);
+        }
+    }
+
+    fn bit(&self, position: usize) -> u8 {
+        self.get(position)
+    }
+
+    fn flip(&mut self, position: usize) {
+        self.toggle(position);
+    }
+
+    fn xor_with(&mut self, other: &Self) {
+        BitVector::xor_with(self, other);
+    }
+}
+
+#[derive(Clone)]
+pub struct LFSR<S: RegisterState = u32> {
+    state: S,
+    highest_bit: usize,
+    taps: S,
+}
+
+impl<S: RegisterState> LFSR<S> {
+    pub fn new(taps: S, degree: usize) -> LFSR<S> {
+        let highest_bit = degree - 1;
+        LFSR {
+            state: S::zero(degree),
+            highest_bit,
+            taps,
+        }
+    }
+
+    pub fn degree(&self) -> usize {
+        self.highest_bit + 1
+    }
+
+    pub fn taps(&self) -> &S {
+        &self.taps
+    }
+
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    pub fn set_state(&mut self, state: S) {
+        self.state = state;
+    }
+
+    pub fn clock(&mut self) -> u8 {
+        let bit = self.state.lowest_bit();
+        let feedback = self.state.and_parity(&self.taps);
+        self.state.shift_in(feedback, self.highest_bit);
+        bit
+    }
+
+    pub fn generate(&mut self, seed: S, length: u64) -> Vec<u8> {
+        self.state = seed;
+        let mut output = vec![0u8; length as usize];
+
+        for bit in output.iter_mut() {
+            *bit = self.clock();
+        }
+
+        output
+    }
+
+    // Row t expresses output bit t as a sum of initial state bits, found by clocking symbolically.
+    pub fn output_equations(&self, length: usize) -> Vec<BitVector> {
+        let degree = self.degree();
+        let mut cells: VecDeque<BitVector> = (0..degree)
+            .map(|i| {
+                let mut cell = BitVector::new(degree);
+                cell.set(i, 1);
+                cell
+            })
+            .collect();
+
+        let mut equations = Vec::with_capacity(length);
+        for _ in 0..length {
+            let mut feedback = BitVector::new(degree);
+            for (i, cell) in cells.iter().enumerate() {
+                if self.taps.bit(i) == 1 {
+                    feedback.xor_with(cell);
+                }
+            }
+            equations.push(cells.pop_front().unwrap());
+            cells.push_back(feedback);
+        }
+
+        equations
+    }
+
+    pub fn characteristic_polynomial(&self) -> Gf2Polynomial {
+        let degree = self.degree();
+        let mut exponents = vec![degree];
+        exponents.extend((0..degree).filter(|&i| self.taps.bit(i) == 1));
+        Gf2Polynomial::from_exponents(&exponents)
+    }
+
+    // Output bit t of the register equals the dot product of the seed with x^t mod f,
+    // so the state `steps` clocks ahead is read off without clocking through them.
+    pub fn state_after(&self, seed: &S, steps: u64) -> S {
+        let degree = self.degree();
+        let f = self.characteristic_polynomial();
+        let x = Gf2Polynomial::x();
+        let mut power = x.pow_mod(steps as u128, &f);
+        let mut state = S::zero(degree);
+
+        for i in 0..degree {
+            let bit = (0..degree).fold(0, |bit, k| bit ^ (power.coefficient(k) & seed.bit(k)));
+            if bit == 1 {
+                state.flip(i);
+            }
+            power = power.mul_mod(&x, &f);
+        }
+
+        state
+    }
+}
+
+impl<S: RegisterState> LFSR<S> {
+    pub fn from_polynomial(polynomial: &FeedbackPolynomial) -> LFSR<S> {
+        LFSR::new(polynomial.fibonacci_taps(), polynomial.degree())
+    }
+}
+
+#[derive(Clone)]
+pub struct GaloisLFSR<S: RegisterState = u32> {
+    state: S,
+    highest_bit: usize,
+    mask: S,
+}
+
+impl<S: RegisterState> GaloisLFSR<S> {
+    pub fn new(mask: S, degree: usize) -> GaloisLFSR<S> {
+        GaloisLFSR {
+            state: S::zero(degree),
+            highest_bit: degree - 1,
+            mask,
+        }
+    }
+
+    pub fn from_polynomial(polynomial: &FeedbackPolynomial) -> GaloisLFSR<S> {
+        GaloisLFSR::new(polynomial.galois_mask(), polynomial.degree())
+    }
+
+    pub fn degree(&self) -> usize {
+        self.highest_bit + 1
+    }
+
+    pub fn mask(&self) -> &S {
+        &self.mask
+    }
+
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    pub fn generate(&mut self, seed: S, length: u64) -> Vec<u8> {
+        self.state = seed;
+        let mut output = vec![0u8; length as usize];
+
+        for bit in output.iter_mut() {
+            *bit = self.state.lowest_bit();
+            self.state.shift_in(0, self.highest_bit);
+            if *bit == 1 {
+                self.state.xor_with(&self.mask);
+            }
+        }
+
+        output
+    }
+}
+
+// The Fibonacci state holds the next `degree` output bits, while the Galois state
+// bit k is output bit k with the feedback of the earlier outputs folded in.
+pub fn fibonacci_to_galois_state<S: RegisterState>(state: &S, polynomial: &FeedbackPolynomial) -> S {
+    let degree = polynomial.degree();
+    let mask: S = polynomial.galois_mask();
+    let mut galois = S::zero(degree);
+
+    for k in 0..degree {
+        let mut bit = state.bit(k);
+        for i in 0..k {
+            bit ^= mask.bit(k - 1 - i) & state.bit(i);
+        }
+        if bit == 1 {
+            galois.flip(k);
+        }
+    }
+
+    galois
+}
+
+pub fn galois_to_fibonacci_state<S: RegisterState>(state: &S, polynomial: &FeedbackPolynomial) -> S {
+    let degree = polynomial.degree();
+    let mask: S = polynomial.galois_mask();
+    let mut fibonacci = S::zero(degree);
+
+    for k in 0..degree {
+        let mut bit = state.bit(k);
+        for i in 0..k {
+            bit ^= mask.bit(k - 1 - i) & fibonacci.bit(i);
+        }
+        if bit == 1 {
+            fibonacci.flip(k);
+        }
+    }
+
+    fibonacci
+}
@@ -0,0 +1,26 @@
+pub mod affine_bigram;
+pub mod alphabet;
+pub mod berlekamp_massey;
+pub mod bits;
+pub mod bitstream;
+pub mod boolean;
+pub mod entropy;
+pub mod errors;
+pub mod fast_correlation;
+pub mod fitness;
+pub mod geffe;
+pub mod geffe_attack;
+pub mod generators;
+pub mod gf2;
+pub mod kasiski;
+pub mod lfsr;
+pub mod linear_system;
+pub mod modarith;
+pub mod polynomial;
+pub mod profile;
+pub mod randomness;
+pub mod refine;
+pub mod rng;
+pub mod statistics;
+pub mod text;
+pub mod vigenere;
@@ -0,0 +1,141 @@
+use crate::bits::BitVector;
+
+// A system of linear equations over GF(2): each row is a coefficient vector and a right-hand side bit.
+#[derive(Debug, Clone)]
+pub struct LinearSystem {
+    variables: usize,
+    rows: Vec<(BitVector, u8)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Solution {
+    Inconsistent,
+    Unique(BitVector),
+    // every solution is `particular` plus a combination of the `null_space` basis vectors
+    Underdetermined { particular: BitVector, null_space: Vec<BitVector> },
+}
+
+impl Solution {
+    // number of variables left free (0 unless underdetermined)
+    pub fn free_bits(&self) -> usize {
+        match self {
+            Solution::Underdetermined { null_space, .. } => null_space.len(),
+            _ => 0,
+        }
+    }
+
+    // All solutions, or None if there are more than 2^max_free_bits of them.
+    pub fn enumerate(&self, max_free_bits: usize) -> Option<Vec<BitVector>> {
+        match self {
+            Solution::Inconsistent => Some(Vec::new()),
+            Solution::Unique(x) => Some(vec![x.clone()]),
+            Solution::Underdetermined { particular, null_space } => {
+                if null_space.len() > max_free_bits {
+                    return None;
+                }
+
+                let mut solutions = vec![particular.clone()];
+                for basis in null_space {
+                    let shifted: Vec<BitVector> = solutions
+                        .iter()
+                        .map(|x| {
+                            let mut y = x.clone();
+                            y.xor_with(basis);
+                            y
+                        })
+                        .collect();
+                    solutions.extend(shifted);
+                }
+                Some(solutions)
+            }
+        }
+    }
+}
+
+impl LinearSystem {
+    pub fn new(variables: usize) -> LinearSystem {
+        LinearSystem { variables, rows: Vec::new() }
+    }
+
+    pub fn variables(&self) -> usize {
+        self.variables
+    }
+
+    pub fn equations(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn add_equation(&mut self, coefficients: BitVector, rhs: u8) {
+        assert_eq!(coefficients.len(), self.variables, "equation width does not match the number of variables");
+        self.rows.push((coefficients, rhs & 1));
+    }
+
+    // Reduced row echelon form; returns the rows and the pivot column of each non-zero row.
+    fn eliminate(&self) -> (Vec<(BitVector, u8)>, Vec<usize>) {
+        let mut rows = self.rows.clone();
+        let mut pivots = Vec::new();
+
+        for column in 0..self.variables {
+            let rank = pivots.len();
+            let Some(pivot) = (rank..rows.len()).find(|&r| rows[r].0.get(column) == 1) else {
+                continue;
+            };
+            rows.swap(rank, pivot);
+
+            let (pivot_row, pivot_rhs) = rows[rank].clone();
+            for (r, (row, rhs)) in rows.iter_mut().enumerate() {
+                if r != rank && row.get(column) == 1 {
+                    row.xor_with(&pivot_row);
+                    *rhs ^= pivot_rhs;
+                }
+            }
+            pivots.push(column);
+        }
+
+        (rows, pivots)
+    }
+
+    pub fn rank(&self) -> usize {
+        self.eliminate().1.len()
+    }
+
+    pub fn solve(&self) -> Solution {
+        let (rows, pivots) = self.eliminate();
+        let rank = pivots.len();
+
+        // after elimination the rows past the rank are all zero, so a set rhs there is 0 = 1
+        if rows[rank..].iter().any(|&(_, rhs)| rhs == 1) {
+            return Solution::Inconsistent;
+        }
+
+        let mut particular = BitVector::new(self.variables);
+        for (r, &column) in pivots.iter().enumerate() {
+            particular.set(column, rows[r].1);
+        }
+
+        if rank == self.variables {
+            return Solution::Unique(particular);
+        }
+
+        let mut is_pivot = vec![false; self.variables];
+        for &column in &pivots {
+            is_pivot[column] = true;
+        }
+
+        let null_space = (0..self.variables)
+            .filter(|&free| !is_pivot[free])
+            .map(|free| {
+                let mut basis = BitVector::new(self.variables);
+                basis.set(free, 1);
+                for (r, &column) in pivots.iter().enumerate() {
+                    if rows[r].0.get(free) == 1 {
+                        basis.set(column, 1);
+                    }
+                }
+                basis
+            })
+            .collect();
+
+        Solution::Underdetermined { particular, null_space }
+    }
+}
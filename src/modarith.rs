@@ -0,0 +1,181 @@
+pub type Matrix2 = [[i64; 2]; 2];
+
+pub fn gcd(a: i64, b: i64) -> i64 {
+    extended_gcd(a, b).0
+}
+
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1, mut y0, mut y1) = (1, 0, 0, 1);
+
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+
+    if r0 < 0 {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+pub fn inverse(a: i64, n: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(n), n);
+    if g == 1 {
+        Some(x.rem_euclid(n))
+    } else {
+        None
+    }
+}
+
+pub fn solve_congruence(a: i64, b: i64, n: i64) -> Vec<i64> {
+    let a = a.rem_euclid(n);
+    let b = b.rem_euclid(n);
+    let g = gcd(a, n);
+
+    if b % g != 0 {
+        return Vec::new();
+    }
+
+    let reduced = n / g;
+    let root = match inverse(a / g, reduced) {
+        Some(inverse) => (inverse * (b / g)).rem_euclid(reduced),
+        None => 0,
+    };
+
+    (0..g).map(|i| root + i * reduced).collect()
+}
+
+pub fn matrix_mul(a: &Matrix2, b: &Matrix2, n: i64) -> Matrix2 {
+    let mut result = [[0; 2]; 2];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, cell) in row.iter_mut().enumerate() {
+            *cell = (a[i][0] * b[0][j] + a[i][1] * b[1][j]).rem_euclid(n);
+        }
+    }
+    result
+}
+
+pub fn matrix_determinant(m: &Matrix2, n: i64) -> i64 {
+    (m[0][0] * m[1][1] - m[0][1] * m[1][0]).rem_euclid(n)
+}
+
+pub fn matrix_inverse(m: &Matrix2, n: i64) -> Option<Matrix2> {
+    let inverse_determinant = inverse(matrix_determinant(m, n), n)?;
+    let adjugate = [[m[1][1], -m[0][1]], [-m[1][0], m[0][0]]];
+
+    let mut result = [[0; 2]; 2];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, cell) in row.iter_mut().enumerate() {
+            *cell = (adjugate[i][j] * inverse_determinant).rem_euclid(n);
+        }
+    }
+    Some(result)
+}
+
+fn mul_mod_u64(a: u64, b: u64, n: u64) -> u64 {
+    ((a as u128 * b as u128) % n as u128) as u64
+}
+
+fn pow_mod_u64(mut base: u64, mut exponent: u64, n: u64) -> u64 {
+    let mut result = 1 % n;
+    base %= n;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod_u64(result, base, n);
+        }
+        base = mul_mod_u64(base, base, n);
+        exponent >>= 1;
+    }
+    result
+}
+
+pub fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+    for &p in &BASES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    let mut d = n - 1;
+    let mut s = 0;
+    while d.is_multiple_of(2) {
+        d /= 2;
+        s += 1;
+    }
+
+    'witness: for &a in &BASES {
+        let mut x = pow_mod_u64(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod_u64(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+
+    true
+}
+
+fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn pollard_rho(n: u64) -> u64 {
+    for c in 1.. {
+        let f = |x: u64| (mul_mod_u64(x, x, n) + c) % n;
+        let (mut x, mut y, mut d) = (2u64, 2u64, 1u64);
+        while d == 1 {
+            x = f(x);
+            y = f(f(y));
+            d = gcd_u64(x.abs_diff(y), n);
+        }
+        if d != n {
+            return d;
+        }
+    }
+    unreachable!()
+}
+
+pub fn factorize(mut n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+
+    for p in [2u64, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        while n > 1 && n.is_multiple_of(p) {
+            factors.push(p);
+            n /= p;
+        }
+    }
+
+    let mut pending = vec![n];
+    while let Some(m) = pending.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime(m) {
+            factors.push(m);
+            continue;
+        }
+        let d = pollard_rho(m);
+        pending.push(d);
+        pending.push(m / d);
+    }
+
+    factors.sort_unstable();
+    factors
+}
@@ -0,0 +1,125 @@
+use std::fmt;
+
+use crate::lfsr::RegisterState;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FeedbackPolynomial {
+    exponents: Vec<usize>,
+}
+
+impl FeedbackPolynomial {
+    pub fn from_exponents(exponents: &[usize]) -> Option<FeedbackPolynomial> {
+        let mut exponents = exponents.to_vec();
+        exponents.sort_unstable_by(|a, b| b.cmp(a));
+        exponents.dedup();
+
+        if exponents.first().copied().unwrap_or(0) == 0 || exponents.last() != Some(&0) {
+            return None;
+        }
+
+        Some(FeedbackPolynomial { exponents })
+    }
+
+    pub fn from_taps<S: RegisterState>(taps: &S, degree: usize) -> Option<FeedbackPolynomial> {
+        let mut exponents = vec![degree];
+        exponents.extend((0..degree).filter(|&i| taps.bit(i) == 1));
+        FeedbackPolynomial::from_exponents(&exponents)
+    }
+
+    pub fn from_hex(hex: &str) -> Option<FeedbackPolynomial> {
+        let digits = hex.trim().trim_start_matches("0x").trim_start_matches("0X");
+        let mut exponents = Vec::new();
+
+        for (position, digit) in digits.chars().rev().enumerate() {
+            let value = digit.to_digit(16)?;
+            exponents.extend((0..4).filter(|bit| value >> bit & 1 == 1).map(|bit| position * 4 + bit));
+        }
+
+        FeedbackPolynomial::from_exponents(&exponents)
+    }
+
+    pub fn parse(text: &str) -> Option<FeedbackPolynomial> {
+        let text = text.trim();
+        if text.starts_with("0x") || text.starts_with("0X") {
+            return FeedbackPolynomial::from_hex(text);
+        }
+
+        if text.contains('x') {
+            let exponents = text
+                .split('+')
+                .map(|term| match term.trim() {
+                    "1" => Some(0),
+                    "x" => Some(1),
+                    term => term.strip_prefix("x^")?.parse().ok(),
+                })
+                .collect::<Option<Vec<usize>>>()?;
+            return FeedbackPolynomial::from_exponents(&exponents);
+        }
+
+        let exponents = text
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|field| !field.is_empty())
+            .map(|field| field.parse().ok())
+            .collect::<Option<Vec<usize>>>()?;
+        FeedbackPolynomial::from_exponents(&exponents)
+    }
+
+    pub fn degree(&self) -> usize {
+        self.exponents[0]
+    }
+
+    pub fn exponents(&self) -> &[usize] {
+        &self.exponents
+    }
+
+    pub fn coefficient(&self, exponent: usize) -> u8 {
+        self.exponents.contains(&exponent) as u8
+    }
+
+    pub fn reciprocal(&self) -> FeedbackPolynomial {
+        let degree = self.degree();
+        let exponents: Vec<usize> = self.exponents.iter().map(|&e| degree - e).collect();
+        FeedbackPolynomial::from_exponents(&exponents).unwrap()
+    }
+
+    pub fn fibonacci_taps<S: RegisterState>(&self) -> S {
+        let mut taps = S::zero(self.degree());
+        for &e in &self.exponents[1..] {
+            taps.flip(e);
+        }
+        taps
+    }
+
+    pub fn galois_mask<S: RegisterState>(&self) -> S {
+        let degree = self.degree();
+        let mut mask = S::zero(degree);
+        for &e in &self.exponents[1..] {
+            mask.flip(degree - 1 - e);
+        }
+        mask
+    }
+
+    pub fn to_hex(&self) -> String {
+        let mut nibbles = vec![0u8; self.degree() / 4 + 1];
+        for &e in &self.exponents {
+            nibbles[e / 4] |= 1 << (e % 4);
+        }
+        let digits: String = nibbles.iter().rev().map(|&n| char::from_digit(n as u32, 16).unwrap()).collect();
+        format!("0x{}", digits)
+    }
+}
+
+impl fmt::Display for FeedbackPolynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let terms: Vec<String> = self
+            .exponents
+            .iter()
+            .map(|&e| match e {
+                0 => "1".to_string(),
+                1 => "x".to_string(),
+                e => format!("x^{}", e),
+            })
+            .collect();
+        write!(f, "{}", terms.join(" + "))
+    }
+}
@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::alphabet::Alphabet;
+use crate::entropy::count_letters_probabilities;
+use crate::errors::invalid_data;
+use crate::text::{get_letter_frequency, process_file};
+
+#[derive(Debug, Clone)]
+pub struct LanguageProfile {
+    alphabet: Alphabet,
+    unigrams: Vec<f64>,
+    bigrams: HashMap<String, f64>,
+    trigrams: Option<HashMap<String, f64>>,
+}
+
+fn ngram_probabilities(letters: &[char], n: usize) -> HashMap<String, f64> {
+    let mut frequencies: HashMap<String, i64> = HashMap::new();
+    for window in letters.windows(n) {
+        *frequencies.entry(window.iter().collect()).or_insert(0) += 1;
+    }
+
+    let total: i64 = frequencies.values().sum();
+    frequencies
+        .into_iter()
+        .map(|(ngram, count)| (ngram, count as f64 / total as f64))
+        .collect()
+}
+
+impl LanguageProfile {
+    pub fn from_corpus(text: &str, alphabet: &Alphabet, with_trigrams: bool) -> LanguageProfile {
+        let letters: Vec<char> = text.chars().filter(|&c| alphabet.contains(c)).collect();
+        let filtered: String = letters.iter().collect();
+
+        let probabilities = count_letters_probabilities(&get_letter_frequency(&filtered));
+        let unigrams = alphabet
+            .letters()
+            .iter()
+            .map(|c| probabilities.get(c).copied().unwrap_or(0.0))
+            .collect();
+
+        LanguageProfile {
+            alphabet: alphabet.clone(),
+            unigrams,
+            bigrams: ngram_probabilities(&letters, 2),
+            trigrams: if with_trigrams { Some(ngram_probabilities(&letters, 3)) } else { None },
+        }
+    }
+
+    pub fn from_file(input_file: &str, processed_file: &str, alphabet: &Alphabet, with_trigrams: bool) -> io::Result<LanguageProfile> {
+        let processed_text = process_file(input_file, processed_file, true, alphabet)?;
+        Ok(LanguageProfile::from_corpus(&processed_text, alphabet, with_trigrams))
+    }
+
+    pub fn alphabet(&self) -> &Alphabet {
+        &self.alphabet
+    }
+
+    pub fn unigrams(&self) -> &[f64] {
+        &self.unigrams
+    }
+
+    pub fn unigram(&self, c: char) -> f64 {
+        self.alphabet.index_of(c).map_or(0.0, |index| self.unigrams[index])
+    }
+
+    pub fn bigrams(&self) -> &HashMap<String, f64> {
+        &self.bigrams
+    }
+
+    pub fn bigram(&self, bigram: &str) -> f64 {
+        self.bigrams.get(bigram).copied().unwrap_or(0.0)
+    }
+
+    pub fn trigrams(&self) -> Option<&HashMap<String, f64>> {
+        self.trigrams.as_ref()
+    }
+
+    pub fn expected_coincidence(&self) -> f64 {
+        self.unigrams.iter().map(|&p| p * p).sum()
+    }
+
+    pub fn h1(&self) -> f64 {
+        -self.unigrams.iter().filter(|&&p| p > 0.0).map(|&p| p * f64::log2(p)).sum::<f64>()
+    }
+
+    pub fn h2(&self) -> f64 {
+        -self.bigrams.values().filter(|&&p| p > 0.0).map(|&p| p * f64::log2(p)).sum::<f64>() / 2.0
+    }
+
+    pub fn check_alphabet(&self, alphabet: &Alphabet) -> io::Result<()> {
+        if &self.alphabet != alphabet {
+            let expected: String = alphabet.letters().iter().collect();
+            let found: String = self.alphabet.letters().iter().collect();
+            return Err(invalid_data(format!("profile alphabet '{}' does not match '{}'", found, expected)));
+        }
+
+        Ok(())
+    }
+
+    pub fn save(&self, output_file: &str) -> io::Result<()> {
+        let mut file = File::create(Path::new(output_file))?;
+
+        let letters: String = self.alphabet.letters().iter().collect();
+        writeln!(file, "alphabet {}", letters)?;
+
+        for (&c, &p) in self.alphabet.letters().iter().zip(self.unigrams.iter()) {
+            writeln!(file, "unigram {} {}", c, p)?;
+        }
+
+        let mut bigrams: Vec<(&String, &f64)> = self.bigrams.iter().collect();
+        bigrams.sort_by(|a, b| a.0.cmp(b.0));
+        for (bigram, p) in bigrams {
+            writeln!(file, "bigram {} {}", bigram, p)?;
+        }
+
+        if let Some(trigrams) = &self.trigrams {
+            let mut trigrams: Vec<(&String, &f64)> = trigrams.iter().collect();
+            trigrams.sort_by(|a, b| a.0.cmp(b.0));
+            for (trigram, p) in trigrams {
+                writeln!(file, "trigram {} {}", trigram, p)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn load(input_file: &str) -> io::Result<LanguageProfile> {
+        let contents = fs::read_to_string(input_file)?;
+        LanguageProfile::parse(&contents)
+    }
+
+    pub fn parse(contents: &str) -> io::Result<LanguageProfile> {
+        let mut alphabet: Option<Alphabet> = None;
+        let mut unigrams: Vec<Option<f64>> = Vec::new();
+        let mut bigrams = HashMap::new();
+        let mut trigrams: Option<HashMap<String, f64>> = None;
+
+        for (line_number, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let error = |message: &str| invalid_data(format!("line {}: {}", line_number + 1, message));
+
+            if fields[0] == "alphabet" {
+                if alphabet.is_some() || fields.len() != 2 {
+                    return Err(error("expected a single 'alphabet <letters>' line"));
+                }
+                let parsed = Alphabet::new(fields[1]).ok_or_else(|| error("invalid alphabet"))?;
+                unigrams = vec![None; parsed.size()];
+                alphabet = Some(parsed);
+                continue;
+            }
+
+            let alphabet = alphabet.as_ref().ok_or_else(|| error("n-gram before 'alphabet' line"))?;
+            if fields.len() != 3 {
+                return Err(error("expected '<kind> <ngram> <probability>'"));
+            }
+
+            let probability: f64 = fields[2].parse().map_err(|_| error("invalid probability"))?;
+            if !(0.0..=1.0).contains(&probability) {
+                return Err(error("probability out of range"));
+            }
+
+            let ngram = fields[1];
+            if !ngram.chars().all(|c| alphabet.contains(c)) {
+                return Err(error("n-gram contains letters outside the alphabet"));
+            }
+
+            let expected_len = match fields[0] {
+                "unigram" => 1,
+                "bigram" => 2,
+                "trigram" => 3,
+                _ => return Err(error("unknown n-gram kind")),
+            };
+            if ngram.chars().count() != expected_len {
+                return Err(error("n-gram length does not match its kind"));
+            }
+
+            match expected_len {
+                1 => unigrams[alphabet.index_of(ngram.chars().next().unwrap()).unwrap()] = Some(probability),
+                2 => {
+                    bigrams.insert(ngram.to_string(), probability);
+                }
+                _ => {
+                    trigrams.get_or_insert_with(HashMap::new).insert(ngram.to_string(), probability);
+                }
+            }
+        }
+
+        let alphabet = alphabet.ok_or_else(|| invalid_data("missing 'alphabet' line".to_string()))?;
+        let unigrams = unigrams
+            .into_iter()
+            .enumerate()
+            .map(|(index, p)| p.ok_or_else(|| invalid_data(format!("missing unigram for '{}'", alphabet.char_at(index)))))
+            .collect::<io::Result<Vec<f64>>>()?;
+
+        let total: f64 = unigrams.iter().sum();
+        if (total - 1.0).abs() > 0.01 {
+            return Err(invalid_data(format!("unigram probabilities sum to {}, expected 1", total)));
+        }
+
+        Ok(LanguageProfile { alphabet, unigrams, bigrams, trigrams })
+    }
+}
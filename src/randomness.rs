@@ -0,0 +1,395 @@
+use std::f64::consts::{LN_2, PI, SQRT_2};
+
+use crate::berlekamp_massey::linear_complexity as sequence_linear_complexity;
+use crate::statistics::{erfc, igamc, normal_cdf};
+
+// Statistical tests after NIST SP 800-22 rev. 1a. Every test takes the sequence as 0/1 bytes and
+// returns its p-value(s), or None when the sequence is too short for the test's approximations.
+
+pub const SIGNIFICANCE: f64 = 0.01;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestResult {
+    pub name: &'static str,
+    pub p_values: Vec<f64>,
+}
+
+impl TestResult {
+    pub fn passed(&self, significance: f64) -> bool {
+        self.p_values.iter().all(|&p| p >= significance)
+    }
+}
+
+fn ones(bits: &[u8]) -> usize {
+    bits.iter().filter(|&&b| b == 1).count()
+}
+
+pub fn monobit(bits: &[u8]) -> Option<f64> {
+    if bits.is_empty() {
+        return None;
+    }
+    let n = bits.len() as f64;
+    let sum = 2.0 * ones(bits) as f64 - n;
+    Some(erfc(sum.abs() / n.sqrt() / SQRT_2))
+}
+
+pub fn block_frequency(bits: &[u8], block_len: usize) -> Option<f64> {
+    let blocks = bits.len() / block_len.max(1);
+    if block_len == 0 || blocks == 0 {
+        return None;
+    }
+
+    let chi_squared: f64 = bits
+        .chunks_exact(block_len)
+        .map(|block| {
+            let pi = ones(block) as f64 / block_len as f64;
+            (pi - 0.5).powi(2)
+        })
+        .sum::<f64>()
+        * 4.0
+        * block_len as f64;
+
+    Some(igamc(blocks as f64 / 2.0, chi_squared / 2.0))
+}
+
+pub fn runs(bits: &[u8]) -> Option<f64> {
+    if bits.len() < 2 {
+        return None;
+    }
+    let n = bits.len() as f64;
+    let pi = ones(bits) as f64 / n;
+
+    // the frequency prerequisite: a sequence this biased fails outright
+    if (pi - 0.5).abs() >= 2.0 / n.sqrt() {
+        return Some(0.0);
+    }
+
+    let runs = 1 + bits.windows(2).filter(|w| w[0] != w[1]).count();
+    let expected = 2.0 * n * pi * (1.0 - pi);
+    Some(erfc((runs as f64 - expected).abs() / (2.0 * (2.0 * n).sqrt() * pi * (1.0 - pi))))
+}
+
+pub fn longest_run(bits: &[u8]) -> Option<f64> {
+    let n = bits.len();
+    // block length, the longest-run classes (first and last are open-ended) and their probabilities
+    let (block_len, first, probabilities): (usize, usize, &[f64]) = if n >= 750_000 {
+        (10_000, 10, &[0.0882, 0.2092, 0.2483, 0.1933, 0.1208, 0.0675, 0.0727])
+    } else if n >= 6272 {
+        (128, 4, &[0.1174, 0.2430, 0.2493, 0.1752, 0.1027, 0.1124])
+    } else if n >= 128 {
+        (8, 1, &[0.2148, 0.3672, 0.2305, 0.1875])
+    } else {
+        return None;
+    };
+
+    let mut counts = vec![0usize; probabilities.len()];
+    for block in bits.chunks_exact(block_len) {
+        let mut longest = 0;
+        let mut current = 0;
+        for &b in block {
+            current = if b == 1 { current + 1 } else { 0 };
+            longest = longest.max(current);
+        }
+        counts[longest.clamp(first, first + probabilities.len() - 1) - first] += 1;
+    }
+
+    let blocks = (n / block_len) as f64;
+    let chi_squared: f64 = counts
+        .iter()
+        .zip(probabilities)
+        .map(|(&count, &p)| (count as f64 - blocks * p).powi(2) / (blocks * p))
+        .sum();
+
+    Some(igamc((probabilities.len() - 1) as f64 / 2.0, chi_squared / 2.0))
+}
+
+// Counts of every overlapping m-bit pattern, the sequence wrapped around at the end
+fn pattern_counts(bits: &[u8], m: usize) -> Vec<usize> {
+    let mut counts = vec![0usize; 1 << m];
+    if m == 0 {
+        counts[0] = bits.len();
+        return counts;
+    }
+
+    let n = bits.len();
+    for i in 0..n {
+        let pattern = (0..m).fold(0, |acc, j| (acc << 1) | bits[(i + j) % n] as usize);
+        counts[pattern] += 1;
+    }
+    counts
+}
+
+fn psi_squared(bits: &[u8], m: usize) -> f64 {
+    if m == 0 {
+        return 0.0;
+    }
+    let n = bits.len() as f64;
+    let sum: f64 = pattern_counts(bits, m).iter().map(|&c| (c * c) as f64).sum();
+    sum * (1u64 << m) as f64 / n - n
+}
+
+pub fn serial(bits: &[u8], m: usize) -> Option<(f64, f64)> {
+    if m < 2 || m >= bits.len() {
+        return None;
+    }
+
+    let (psi_m, psi_m1, psi_m2) = (psi_squared(bits, m), psi_squared(bits, m - 1), psi_squared(bits, m - 2));
+    // both differences are non-negative in exact arithmetic but can round to a tiny negative value
+    let delta = (psi_m - psi_m1).max(0.0);
+    let delta2 = (psi_m - 2.0 * psi_m1 + psi_m2).max(0.0);
+
+    Some((
+        igamc((1u64 << (m - 2)) as f64, delta / 2.0),
+        igamc((1u64 << (m - 2)) as f64 / 2.0, delta2 / 2.0),
+    ))
+}
+
+pub fn approximate_entropy(bits: &[u8], m: usize) -> Option<f64> {
+    if m == 0 || m + 1 >= bits.len() {
+        return None;
+    }
+    let n = bits.len() as f64;
+
+    let phi = |m: usize| -> f64 {
+        pattern_counts(bits, m)
+            .iter()
+            .filter(|&&c| c > 0)
+            .map(|&c| {
+                let p = c as f64 / n;
+                p * p.ln()
+            })
+            .sum()
+    };
+
+    let entropy = phi(m) - phi(m + 1);
+    let chi_squared = (2.0 * n * (LN_2 - entropy)).max(0.0);
+    Some(igamc((1u64 << (m - 1)) as f64, chi_squared / 2.0))
+}
+
+fn cusum_p_value(n: f64, z: f64) -> f64 {
+    let sqrt_n = n.sqrt();
+
+    let mut sum1 = 0.0;
+    let mut k = ((-n / z + 1.0) / 4.0).trunc();
+    while k <= (n / z - 1.0) / 4.0 {
+        sum1 += normal_cdf((4.0 * k + 1.0) * z / sqrt_n) - normal_cdf((4.0 * k - 1.0) * z / sqrt_n);
+        k += 1.0;
+    }
+
+    let mut sum2 = 0.0;
+    let mut k = ((-n / z - 3.0) / 4.0).trunc();
+    while k <= (n / z - 1.0) / 4.0 {
+        sum2 += normal_cdf((4.0 * k + 3.0) * z / sqrt_n) - normal_cdf((4.0 * k + 1.0) * z / sqrt_n);
+        k += 1.0;
+    }
+
+    1.0 - sum1 + sum2
+}
+
+// forward and backward p-values
+pub fn cumulative_sums(bits: &[u8]) -> Option<(f64, f64)> {
+    if bits.is_empty() {
+        return None;
+    }
+
+    let max_excursion = |sequence: &mut dyn Iterator<Item = &u8>| {
+        let mut sum = 0i64;
+        let mut max = 0i64;
+        for &b in sequence {
+            sum += 2 * b as i64 - 1;
+            max = max.max(sum.abs());
+        }
+        max as f64
+    };
+
+    let n = bits.len() as f64;
+    let forward = max_excursion(&mut bits.iter());
+    let backward = max_excursion(&mut bits.iter().rev());
+    Some((cusum_p_value(n, forward), cusum_p_value(n, backward)))
+}
+
+pub fn linear_complexity(bits: &[u8], block_len: usize) -> Option<f64> {
+    const PROBABILITIES: [f64; 7] = [0.010417, 0.03125, 0.125, 0.5, 0.25, 0.0625, 0.020833];
+
+    let blocks = bits.len() / block_len.max(1);
+    if block_len < 2 || blocks == 0 {
+        return None;
+    }
+
+    let m = block_len as f64;
+    let sign = if block_len.is_multiple_of(2) { 1.0 } else { -1.0 };
+    let mean = m / 2.0 + (9.0 - sign) / 36.0 - (m / 3.0 + 2.0 / 9.0) / 2f64.powf(m);
+
+    let mut counts = [0usize; 7];
+    for block in bits.chunks_exact(block_len) {
+        let t = sign * (sequence_linear_complexity(block) as f64 - mean) + 2.0 / 9.0;
+        let class = match t {
+            t if t <= -2.5 => 0,
+            t if t <= -1.5 => 1,
+            t if t <= -0.5 => 2,
+            t if t <= 0.5 => 3,
+            t if t <= 1.5 => 4,
+            t if t <= 2.5 => 5,
+            _ => 6,
+        };
+        counts[class] += 1;
+    }
+
+    let n = blocks as f64;
+    let chi_squared: f64 = counts
+        .iter()
+        .zip(PROBABILITIES)
+        .map(|(&count, p)| (count as f64 - n * p).powi(2) / (n * p))
+        .sum();
+
+    Some(igamc(3.0, chi_squared / 2.0))
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Complex {
+    re: f64,
+    im: f64,
+}
+
+impl Complex {
+    fn new(re: f64, im: f64) -> Complex {
+        Complex { re, im }
+    }
+
+    fn from_angle(angle: f64) -> Complex {
+        Complex::new(angle.cos(), angle.sin())
+    }
+
+    fn mul(self, other: Complex) -> Complex {
+        Complex::new(self.re * other.re - self.im * other.im, self.re * other.im + self.im * other.re)
+    }
+
+    fn conj(self) -> Complex {
+        Complex::new(self.re, -self.im)
+    }
+
+    fn abs(self) -> f64 {
+        self.re.hypot(self.im)
+    }
+}
+
+// In-place radix-2 transform; the length must be a power of two.
+fn fft_pow2(values: &mut [Complex], inverse: bool) {
+    let n = values.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = if inverse { 2.0 * PI } else { -2.0 * PI } / len as f64;
+        let step = Complex::from_angle(angle);
+        for start in (0..n).step_by(len) {
+            let mut w = Complex::new(1.0, 0.0);
+            for k in 0..len / 2 {
+                let u = values[start + k];
+                let v = values[start + k + len / 2].mul(w);
+                values[start + k] = Complex::new(u.re + v.re, u.im + v.im);
+                values[start + k + len / 2] = Complex::new(u.re - v.re, u.im - v.im);
+                w = w.mul(step);
+            }
+        }
+        len <<= 1;
+    }
+
+    if inverse {
+        for value in values.iter_mut() {
+            *value = Complex::new(value.re / n as f64, value.im / n as f64);
+        }
+    }
+}
+
+// DFT of any length via Bluestein's chirp-z convolution
+fn dft(input: &[f64]) -> Vec<Complex> {
+    let n = input.len();
+    if n.is_power_of_two() {
+        let mut values: Vec<Complex> = input.iter().map(|&x| Complex::new(x, 0.0)).collect();
+        fft_pow2(&mut values, false);
+        return values;
+    }
+
+    // k^2 is reduced mod 2n to keep the chirp angles accurate for long sequences
+    let chirp: Vec<Complex> = (0..n)
+        .map(|k| Complex::from_angle(-PI * ((k as u128 * k as u128) % (2 * n as u128)) as f64 / n as f64))
+        .collect();
+
+    let size = (2 * n - 1).next_power_of_two();
+    let mut a = vec![Complex::new(0.0, 0.0); size];
+    let mut b = vec![Complex::new(0.0, 0.0); size];
+    for k in 0..n {
+        a[k] = chirp[k].mul(Complex::new(input[k], 0.0));
+        b[k] = chirp[k].conj();
+        if k > 0 {
+            b[size - k] = chirp[k].conj();
+        }
+    }
+
+    fft_pow2(&mut a, false);
+    fft_pow2(&mut b, false);
+    for (x, y) in a.iter_mut().zip(&b) {
+        *x = x.mul(*y);
+    }
+    fft_pow2(&mut a, true);
+
+    (0..n).map(|k| a[k].mul(chirp[k])).collect()
+}
+
+pub fn spectral(bits: &[u8]) -> Option<f64> {
+    if bits.len() < 2 {
+        return None;
+    }
+    let n = bits.len() as f64;
+    let signal: Vec<f64> = bits.iter().map(|&b| 2.0 * b as f64 - 1.0).collect();
+    let spectrum = dft(&signal);
+
+    let threshold = ((1.0f64 / 0.05).ln() * n).sqrt();
+    let expected = 0.95 * n / 2.0;
+    let below = spectrum[..bits.len() / 2].iter().filter(|c| c.abs() < threshold).count() as f64;
+    let d = (below - expected) / (n * 0.95 * 0.05 / 4.0).sqrt();
+    Some(erfc(d.abs() / SQRT_2))
+}
+
+// Every test with parameters scaled to the sequence length; tests the sequence is too short for
+// are left out.
+pub fn run_all(bits: &[u8]) -> Vec<TestResult> {
+    let n = bits.len();
+    let log_n = (usize::BITS - n.max(1).leading_zeros()) as usize - 1;
+    let m = log_n.saturating_sub(6).clamp(2, 16);
+    let block_len = (n / 100 + 1).max(20);
+    // SP 800-22 uses 500-bit blocks on 10^6 bits; shorter sequences get shorter blocks, 20 of them at least
+    let lc_block_len = (n / 20).min(500);
+
+    let tests: Vec<(&'static str, Option<Vec<f64>>)> = vec![
+        ("monobit", monobit(bits).map(|p| vec![p])),
+        ("block frequency", block_frequency(bits, block_len).map(|p| vec![p])),
+        ("runs", runs(bits).map(|p| vec![p])),
+        ("longest run of ones", longest_run(bits).map(|p| vec![p])),
+        ("serial", serial(bits, m).map(|(p1, p2)| vec![p1, p2])),
+        ("approximate entropy", approximate_entropy(bits, m).map(|p| vec![p])),
+        ("cumulative sums", cumulative_sums(bits).map(|(p1, p2)| vec![p1, p2])),
+        (
+            "linear complexity",
+            if lc_block_len >= 40 { linear_complexity(bits, lc_block_len).map(|p| vec![p]) } else { None },
+        ),
+        ("spectral (DFT)", spectral(bits).map(|p| vec![p])),
+    ];
+
+    tests
+        .into_iter()
+        .filter_map(|(name, p_values)| p_values.map(|p_values| TestResult { name, p_values }))
+        .collect()
+}
@@ -0,0 +1,60 @@
+use crate::alphabet::Alphabet;
+use crate::fitness::NgramScorer;
+use crate::rng::XorShift64;
+use crate::vigenere::decode;
+
+#[derive(Debug, Clone)]
+pub struct Improvement {
+    pub iteration: usize,
+    pub key: String,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Refinement {
+    pub key: String,
+    pub score: f64,
+    pub improvements: Vec<Improvement>,
+}
+
+pub fn refine_key(
+    text: &str,
+    initial_key: &str,
+    alphabet: &Alphabet,
+    scorer: &NgramScorer,
+    iterations: usize,
+    seed: u64,
+) -> Refinement {
+    let mut rng = XorShift64::new(seed);
+    let mut key: Vec<char> = initial_key.chars().collect();
+    let mut improvements = Vec::new();
+
+    // there is nothing to mutate (nor to decode with) without a key
+    if key.is_empty() {
+        return Refinement { key: String::new(), score: scorer.normalized_score(text), improvements };
+    }
+
+    let mut score = scorer.normalized_score(&decode(text, initial_key, alphabet));
+    if alphabet.size() < 2 {
+        return Refinement { key: initial_key.to_string(), score, improvements };
+    }
+
+    for iteration in 0..iterations {
+        let position = rng.below(key.len());
+        let current = alphabet.index_of(key[position]).unwrap_or(0);
+        let replacement = alphabet.char_at(current + 1 + rng.below(alphabet.size() - 1));
+
+        let mut candidate = key.clone();
+        candidate[position] = replacement;
+        let candidate_key: String = candidate.iter().collect();
+        let candidate_score = scorer.normalized_score(&decode(text, &candidate_key, alphabet));
+
+        if candidate_score > score {
+            key = candidate;
+            score = candidate_score;
+            improvements.push(Improvement { iteration, key: candidate_key, score });
+        }
+    }
+
+    Refinement { key: key.into_iter().collect(), score, improvements }
+}
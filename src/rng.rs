@@ -0,0 +1,24 @@
+// xorshift64 (Marsaglia): fast and reproducible from a seed, which is all the search heuristics and
+// test generators need; not for key material.
+#[derive(Debug, Clone)]
+pub struct XorShift64 {
+    state: u64,
+}
+
+impl XorShift64 {
+    // the all-zero state is a fixed point, so a zero seed is replaced by 1
+    pub fn new(seed: u64) -> XorShift64 {
+        XorShift64 { state: seed.max(1) }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
@@ -3,18 +3,21 @@ use std::fs::File;
 use std::io::{self, BufRead, BufReader, Write};
 use std::path::Path;
 
-#[allow(dead_code)]
+use crate::alphabet::Alphabet;
+
 pub fn is_cyrillic(c: &char) -> bool {
     (*c as u32) >= 0x0400 && (*c as u32) <= 0x04FF
 }
 
-#[allow(dead_code)]
-pub fn preprocess_text(text: &str) -> Option<String> {
+pub fn preprocess_text(text: &str, alphabet: &Alphabet) -> Option<String> {
     let mut processed_text = String::new();
     
     for c in text.chars() {
-        if c.is_alphabetic() && is_cyrillic(&c) {
-            processed_text.push(c.to_lowercase().next().unwrap());
+        let lower = c.to_lowercase().next().unwrap_or(c);
+        // alphabets without ё (the 32-letter Russian one) write it as е
+        let lower = if lower == 'ё' && !alphabet.contains('ё') { 'е' } else { lower };
+        if alphabet.contains(lower) {
+            processed_text.push(lower);
         } else if c == ' ' {
             if processed_text.ends_with(' ') {
                 continue;
@@ -30,12 +33,10 @@ pub fn preprocess_text(text: &str) -> Option<String> {
     }
 }
 
-#[allow(dead_code)]
 pub fn remove_spaces(text: &str) -> String {
     text.chars().filter(|&c| c != ' ' && c != '\n' && c != '\0').collect()
 }
 
-#[allow(dead_code)]
 pub fn get_letter_frequency(text: &str) -> HashMap<char, i64> {
     let mut frequencies: HashMap<char, i64> = HashMap::new();
 
@@ -46,7 +47,6 @@ pub fn get_letter_frequency(text: &str) -> HashMap<char, i64> {
     frequencies
 }
 
-#[allow(dead_code)]
 pub fn print_bigram_frequencies(bigram_frequencies: &HashMap<String, i64>) {
     let mut letters: Vec<char> = bigram_frequencies.keys().flat_map(|s| s.chars()).collect::<Vec<char>>();
     
@@ -79,7 +79,6 @@ pub fn print_bigram_frequencies(bigram_frequencies: &HashMap<String, i64>) {
     println!();
 }
 
-#[allow(dead_code)]
 pub fn print_bigram_probabilities(bigram_frequencies: &HashMap<String, f64>) {
     let mut letters: Vec<char> = bigram_frequencies.keys().flat_map(|s| s.chars()).collect::<Vec<char>>();
     
@@ -112,7 +111,6 @@ pub fn print_bigram_probabilities(bigram_frequencies: &HashMap<String, f64>) {
     println!();
 }
 
-#[allow(dead_code)]
 pub fn print_letters_probabilities(probabilities: &HashMap<char, f64>) {
     let mut sorted_probabilities: Vec<(&char, &f64)> = probabilities.iter().collect();
     sorted_probabilities.sort_by(|a, b| b.1.partial_cmp(a.1).unwrap());
@@ -124,7 +122,6 @@ pub fn print_letters_probabilities(probabilities: &HashMap<char, f64>) {
     println!();
 }
 
-#[allow(dead_code)]
 pub fn print_letter_frequencies(letter_frequencies: &HashMap<char, i64>) {
     let mut sorted_frequencies: Vec<(&char, &i64)> = letter_frequencies.iter().collect();
     sorted_frequencies.sort_by_key(|&(_, frequency)| *frequency);
@@ -136,13 +133,12 @@ pub fn print_letter_frequencies(letter_frequencies: &HashMap<char, i64>) {
     println!();
 }
 
-#[allow(dead_code)]
-pub fn process_file(input_file: &str, output_file: &str, with_spaces: bool) -> io::Result<String> {
+pub fn process_file(input_file: &str, output_file: &str, with_spaces: bool, alphabet: &Alphabet) -> io::Result<String> {
     let file = File::open(input_file)?;
     let path = Path::new(output_file);
     let display = path.display();
     
-    let mut output_file = match File::create(&path) {
+    let mut output_file = match File::create(path) {
         Err(why) => panic!("couldn't create {}: {}", display, why),
         Ok(output_file) => output_file,
     };
@@ -154,13 +150,11 @@ pub fn process_file(input_file: &str, output_file: &str, with_spaces: bool) -> i
         let line = line?;
 
         if !line.trim().is_empty() {
-            let processed_line: Option<String>;
-
-            if with_spaces {
-                processed_line = preprocess_text(&line);
+            let processed_line = if with_spaces {
+                preprocess_text(&line, alphabet)
             } else {
-                processed_line = Some(remove_spaces(&line));
-            }
+                Some(remove_spaces(&line))
+            };
             
             if let Some(processed_line) = processed_line {
                 writeln!(output_file, "{}", processed_line)?;
@@ -172,18 +166,17 @@ pub fn process_file(input_file: &str, output_file: &str, with_spaces: bool) -> i
     Ok(processed_text)
 }
 
-#[allow(dead_code)]
-pub fn coincidence(input_text: &str, alphabet: &str) -> f64 {
-    let text: Vec<char> = input_text.chars().collect();
+pub fn coincidence(input_text: &str, alphabet: &Alphabet) -> f64 {
+    let text: Vec<char> = input_text.chars().filter(|&c| alphabet.contains(c)).collect();
     let mut sum: usize = 0;
 
-    for c in alphabet.chars() {
+    for &c in alphabet.letters() {
         let occurrences = text.iter().filter(|&&x| x == c).count();
-        sum = sum.checked_add(occurrences.checked_mul(occurrences.checked_sub(1).unwrap_or(0)).unwrap_or(0)).unwrap_or(0);
+        sum = sum.checked_add(occurrences.checked_mul(occurrences.saturating_sub(1)).unwrap_or(0)).unwrap_or(0);
     }
 
     let text_len = text.len();
-    let denominator = (text_len.checked_mul(text_len.checked_sub(1).unwrap_or(0)).unwrap_or(0)) as f64;
+    let denominator = (text_len.checked_mul(text_len.saturating_sub(1)).unwrap_or(0)) as f64;
 
     (sum as f64) / denominator
 }
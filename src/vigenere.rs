@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::alphabet::Alphabet;
+use crate::profile::LanguageProfile;
+use crate::text::coincidence;
+
+pub fn encode_char(c: char, key_char: char, alphabet: &Alphabet) -> char {
+    alphabet.add(c, key_char).unwrap_or(c)
+}
+
+pub fn decode_char(c: char, key_char: char, alphabet: &Alphabet) -> char {
+    alphabet.sub(c, key_char).unwrap_or(c)
+}
+
+// None if the key is empty or has a letter outside the alphabet (which would leave text unencrypted).
+pub fn encode(input_text: &str, key: &str, alphabet: &Alphabet) -> Option<String> {
+    let key_chars: Vec<char> = key.chars().collect();
+    if key_chars.is_empty() || !key_chars.iter().all(|&c| alphabet.contains(c)) {
+        return None;
+    }
+
+    let mut encoded_text = String::new();
+
+    for line in input_text.lines() {
+        for (key_index, c) in line.chars().enumerate() {
+            let key_char = key_chars[key_index % key_chars.len()];
+            encoded_text.push(encode_char(c, key_char, alphabet));
+        }
+        encoded_text.push('\n');
+    }
+
+    Some(encoded_text)
+}
+
+pub fn vigenere_encode(input_text: &str, encoded_file: &str, key: &str, alphabet: &Alphabet) -> io::Result<()> {
+    let encoded_text = encode(input_text, key, alphabet)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("key '{}' is not a word of the alphabet", key)))?;
+    let mut encoded_file = File::create(Path::new(encoded_file))?;
+    encoded_file.write_all(encoded_text.as_bytes())
+}
+
+pub fn i_m_theoretical(probabilities: &[f64]) -> f64 {
+    probabilities.iter().map(|&p| p * p).sum()
+}
+
+pub fn calculate_expected_i(probabilities: &[f64]) -> f64 {
+    probabilities.iter().map(|&p| p.powi(2)).sum()
+}
+
+pub fn divide_into_blocks(text: &str, r: usize) -> Vec<String> {
+    // love it :))
+    (0..r).map(|i| text.chars().skip(i).step_by(r).collect()).collect()
+}
+
+pub fn average_coincidence(text: &str, r: usize, alphabet: &Alphabet) -> f64 {
+    let blocks = divide_into_blocks(text, r);
+    let blocks_coincidence: f64 = blocks.iter().map(|block| coincidence(block, alphabet)).sum();
+    blocks_coincidence / blocks.len() as f64
+}
+
+#[derive(Debug, Clone)]
+pub struct KeyLengthCandidate {
+    pub length: usize,
+    pub average_coincidence: f64,
+    pub deviation: f64,
+}
+
+pub fn compute_r(text: &str, profile: &LanguageProfile, min_r: usize, max_r: usize) -> Vec<KeyLengthCandidate> {
+    let expected_i = profile.expected_coincidence();
+
+    let mut candidates: Vec<KeyLengthCandidate> = (min_r.max(1)..=max_r)
+        .map(|r| {
+            let average_coincidence = average_coincidence(text, r, profile.alphabet());
+            KeyLengthCandidate {
+                length: r,
+                average_coincidence,
+                deviation: (expected_i - average_coincidence).abs(),
+            }
+        })
+        .collect();
+
+    candidates.sort_by(|a, b| a.deviation.partial_cmp(&b.deviation).unwrap_or(std::cmp::Ordering::Equal).then(a.length.cmp(&b.length)));
+    candidates
+}
+
+// Share of the gap between the language and random coincidence indices by which a divisor may trail
+// the best period: blocks of a true period scatter far less than this, wrong periods sit near random.
+const PERIOD_TOLERANCE: f64 = 0.25;
+
+pub fn select_key_length(candidates: &[KeyLengthCandidate], profile: &LanguageProfile) -> Option<usize> {
+    let best = candidates.first()?;
+
+    // every multiple of the true period looks as good as the period itself, so among
+    // the candidates that are close to the language index pick the smallest divisor of the best one
+    let expected_i = profile.expected_coincidence();
+    let random_i = 1.0 / profile.alphabet().size() as f64;
+    let tolerance = best.deviation + PERIOD_TOLERANCE * (expected_i - random_i).abs();
+
+    candidates
+        .iter()
+        .filter(|c| c.deviation <= tolerance && best.length.is_multiple_of(c.length))
+        .map(|c| c.length)
+        .min()
+}
+
+pub fn find_most_frequent_letter(profile: &LanguageProfile) -> char {
+    let alphabet = profile.alphabet();
+    profile.unigrams().iter().enumerate().max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(idx, _)| alphabet.char_at(idx))
+        .unwrap_or_else(|| alphabet.char_at(0))
+}
+
+pub fn determine_key_characters(text: &str, r: usize, profile: &LanguageProfile) -> String {
+    let alphabet = profile.alphabet();
+    let blocks = divide_into_blocks(text, r);
+    let most_frequent_language_letter = find_most_frequent_letter(profile);
+    let mut key = String::with_capacity(r);
+
+    for block in blocks {
+        let mut letter_frequencies = HashMap::new();
+        for letter in block.chars().filter(|&c| alphabet.contains(c)) {
+            *letter_frequencies.entry(letter).or_insert(0) += 1;
+        }
+
+        if let Some((&most_frequent_block_letter, _)) = letter_frequencies.iter().max_by_key(|&(_, &count)| count) {
+            let shift = alphabet.sub(most_frequent_block_letter, most_frequent_language_letter).unwrap_or_else(|| alphabet.char_at(0));
+            key.push(shift);
+        } else {
+            key.push(alphabet.char_at(0));
+        }
+    }
+
+    key
+}
+
+pub fn crack_key_mi(text: &str, key_length: usize, profile: &LanguageProfile) -> String {
+    let alphabet = profile.alphabet();
+    let blocks = divide_into_blocks(text, key_length);
+    let mut key = String::new();
+
+    for block in blocks.iter().take(key_length) {
+        let mut counts = vec![0usize; alphabet.size()];
+        for index in block.chars().filter_map(|c| alphabet.index_of(c)) {
+            counts[index] += 1;
+        }
+
+        let mut max_shift = 0;
+        let mut max_m = 0.0;
+
+        for g in 0..alphabet.size() {
+            let mut current_m = 0.0;
+            for (t, probability) in profile.unigrams().iter().enumerate() {
+                let shift_index = (t + g) % alphabet.size();
+                current_m += probability * counts[shift_index] as f64;
+            }
+
+            if current_m > max_m {
+                max_m = current_m;
+                max_shift = g;
+            }
+        }
+
+        key.push(alphabet.char_at(max_shift));
+    }
+
+    key
+}
+
+pub fn decode(text: &str, key: &str, alphabet: &Alphabet) -> String {
+    let key_chars: Vec<char> = key.chars().collect();
+
+    text.chars()
+        .enumerate()
+        .map(|(i, c)| decode_char(c, key_chars[i % key_chars.len()], alphabet))
+        .collect()
+}
@@ -0,0 +1,42 @@
+use symmetric_crypto::affine_bigram::{alphabet, candidate_keys, crack, decode, encode, modulus, preprocess, MOST_FREQUENT_BIGRAMS};
+use symmetric_crypto::profile::LanguageProfile;
+
+const KEY: (u64, u64) = (317, 512);
+
+fn plaintext() -> String {
+    let corpus = std::fs::read_to_string("text_files/entropy/boloto.txt").unwrap();
+    preprocess(&corpus, &alphabet()).chars().skip(20_000).take(4000).collect()
+}
+
+#[test]
+fn encode_decode_round_trip() {
+    let alphabet = alphabet();
+    let plaintext = plaintext();
+    let ciphertext = encode(&plaintext, KEY, &alphabet);
+    assert_eq!(ciphertext.chars().count(), plaintext.chars().count());
+    assert_ne!(ciphertext, plaintext);
+    assert_eq!(decode(&ciphertext, KEY, &alphabet).unwrap(), plaintext);
+
+    // 961 = 31^2, so multiples of 31 have no inverse
+    assert_eq!(modulus(&alphabet), 961);
+    assert_eq!(decode(&ciphertext, (62, 1), &alphabet), None);
+}
+
+#[test]
+fn candidate_keys_contain_key_of_mapped_bigrams() {
+    let alphabet = alphabet();
+    let cipher_bigrams: Vec<String> = MOST_FREQUENT_BIGRAMS[..2].iter().map(|bigram| encode(bigram, KEY, &alphabet)).collect();
+    assert!(candidate_keys(&cipher_bigrams, &MOST_FREQUENT_BIGRAMS[..2], &alphabet).contains(&KEY));
+}
+
+#[test]
+fn crack_recovers_key_and_plaintext() {
+    let alphabet = alphabet();
+    let plaintext = plaintext();
+    let ciphertext = encode(&plaintext, KEY, &alphabet);
+    let expected_i = LanguageProfile::load("text_files/profiles/russian_32.txt").unwrap().expected_coincidence();
+
+    let rated = crack(&ciphertext, &MOST_FREQUENT_BIGRAMS, &alphabet, expected_i);
+    assert_eq!(rated[0].0, KEY);
+    assert_eq!(decode(&ciphertext, rated[0].0, &alphabet).unwrap(), plaintext);
+}
@@ -0,0 +1,76 @@
+use symmetric_crypto::alphabet::{Alphabet, ENGLISH_26, RUSSIAN_32, RUSSIAN_33, UKRAINIAN_33};
+use symmetric_crypto::text::preprocess_text;
+use symmetric_crypto::vigenere::{decode, encode};
+
+const PRESETS: [(&str, &str, usize); 4] = [
+    ("russian-32", RUSSIAN_32, 32),
+    ("russian-33", RUSSIAN_33, 33),
+    ("ukrainian-33", UKRAINIAN_33, 33),
+    ("english-26", ENGLISH_26, 26),
+];
+
+#[test]
+fn presets_by_name() {
+    for (name, letters, size) in PRESETS {
+        let alphabet = Alphabet::from_name(name).unwrap();
+        assert_eq!(alphabet.size(), size);
+        assert_eq!(alphabet, Alphabet::new(letters).unwrap());
+    }
+    assert_eq!(Alphabet::from_name("ru32"), Alphabet::from_name("russian-32"));
+    assert_eq!(Alphabet::from_name("uk33"), Alphabet::from_name("ukrainian-33"));
+    assert!(Alphabet::from_name("klingon").is_none());
+
+    assert!(Alphabet::new("").is_none());
+    assert!(Alphabet::new("abca").is_none());
+}
+
+#[test]
+fn index_and_arithmetic_round_trip() {
+    for (name, _, size) in PRESETS {
+        let alphabet = Alphabet::from_name(name).unwrap();
+        for (i, &a) in alphabet.letters().iter().enumerate() {
+            assert_eq!(alphabet.index_of(a), Some(i));
+            assert_eq!(alphabet.char_at(i), a);
+            assert_eq!(alphabet.char_at(i + size), a);
+
+            for &b in alphabet.letters() {
+                let sum = alphabet.add(a, b).unwrap();
+                assert_eq!(alphabet.sub(sum, b), Some(a), "{}: {} + {}", name, a, b);
+            }
+        }
+
+        assert_eq!(alphabet.index_of('1'), None);
+        assert_eq!(alphabet.add('1', alphabet.char_at(0)), None);
+        assert_eq!(alphabet.sub(alphabet.char_at(0), '1'), None);
+    }
+}
+
+#[test]
+fn vigenere_round_trip_for_each_preset() {
+    for (name, letters, _) in PRESETS {
+        let alphabet = Alphabet::from_name(name).unwrap();
+        let text: String = letters.chars().rev().chain(letters.chars().step_by(3)).collect();
+        let key: String = letters.chars().skip(5).take(4).collect();
+
+        let encoded = encode(&text, &key, &alphabet).unwrap();
+        assert_ne!(encoded.trim_end(), text);
+        assert_eq!(decode(encoded.trim_end(), &key, &alphabet), text, "{}", name);
+    }
+}
+
+#[test]
+fn vigenere_rejects_keys_outside_the_alphabet() {
+    let alphabet = Alphabet::russian_32();
+    assert_eq!(encode("привет", "клю4", &alphabet), None);
+    assert_eq!(encode("привет", "key", &alphabet), None);
+    assert_eq!(encode("привет", "", &alphabet), None);
+    assert_eq!(encode("привет", "ключ", &alphabet).as_deref(), Some("щыжщпэ\n"));
+}
+
+#[test]
+fn preprocessing_keeps_the_letters_of_the_alphabet() {
+    assert_eq!(preprocess_text("Ещё  її, Hi!", &Alphabet::ukrainian_33()).as_deref(), Some("еще її "));
+    assert_eq!(preprocess_text("Ещё  её", &Alphabet::russian_33()).as_deref(), Some("ещё её"));
+    assert_eq!(preprocess_text("Ещё  её", &Alphabet::russian_32()).as_deref(), Some("еще ее"));
+    assert_eq!(preprocess_text("Ещё", &Alphabet::english_26()), None);
+}
@@ -0,0 +1,47 @@
+use symmetric_crypto::berlekamp_massey::{berlekamp_massey, linear_complexity, linear_complexity_profile};
+use symmetric_crypto::lfsr::LFSR;
+use symmetric_crypto::polynomial::FeedbackPolynomial;
+
+fn bits(text: &str) -> Vec<u8> {
+    text.bytes().map(|b| b - b'0').collect()
+}
+
+#[test]
+fn recovers_register_from_twice_its_degree() {
+    for polynomial in ["x^25 + x^3 + 1", "x^26 + x^6 + x^2 + x + 1", "x^31 + x^3 + 1"] {
+        let polynomial = FeedbackPolynomial::parse(polynomial).unwrap();
+        let degree = polynomial.degree();
+        let mut lfsr: LFSR<u32> = LFSR::from_polynomial(&polynomial);
+        let sequence = lfsr.generate(0x0123_4567 & ((1u64 << degree) - 1) as u32, 2 * degree as u64);
+
+        let connection = berlekamp_massey(&sequence);
+        assert_eq!(connection.linear_complexity, degree);
+        assert_eq!(connection.feedback_polynomial(), Some(polynomial.clone()));
+        assert_eq!(connection.taps(), Some(polynomial.fibonacci_taps::<u32>()));
+    }
+}
+
+#[test]
+fn profile_of_worked_example() {
+    // Handbook of Applied Cryptography, table 6.1: C(D) = 1 + D^3 + D^5
+    let sequence = bits("001101110");
+    assert_eq!(linear_complexity_profile(&sequence), [0, 0, 3, 3, 3, 3, 3, 5, 5]);
+    let connection = berlekamp_massey(&sequence);
+    assert_eq!(connection.coefficients, [1, 0, 0, 1, 0, 1]);
+    assert_eq!(connection.feedback_polynomial(), FeedbackPolynomial::from_exponents(&[5, 2, 0]));
+}
+
+#[test]
+fn edge_cases() {
+    assert_eq!(linear_complexity(&[0; 16]), 0);
+    assert_eq!(linear_complexity_profile(&[0; 4]), [0, 0, 0, 0]);
+    assert_eq!(berlekamp_massey(&[0; 16]).feedback_polynomial(), None);
+
+    // a lone 1 after n - 1 zeros can only come from a register of length n
+    assert_eq!(linear_complexity_profile(&bits("00000001")), [0, 0, 0, 0, 0, 0, 0, 8]);
+    assert_eq!(linear_complexity(&[1]), 1);
+
+    assert_eq!(linear_complexity(&[]), 0);
+    assert!(linear_complexity_profile(&[]).is_empty());
+    assert_eq!(berlekamp_massey(&[]).coefficients, [1]);
+}
@@ -0,0 +1,61 @@
+use symmetric_crypto::bitstream::{
+    decode, encode, pack, parse_ascii, parse_hex, read_bits, truncate, unpack, write_bits, BitFormat, BitOrder,
+};
+
+const BITS: [u8; 12] = [1, 0, 1, 1, 0, 0, 1, 0, 1, 1, 1, 0];
+
+#[test]
+fn ascii_ignores_whitespace_and_reports_position() {
+    assert_eq!(parse_ascii("1011 0010\n 111\t0\n").unwrap(), BITS);
+
+    let error = parse_ascii("0101\n01 2 1").unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(error.to_string().contains("line 2, column 4"), "{}", error);
+}
+
+#[test]
+fn hex_digits_are_msb_first() {
+    assert_eq!(parse_hex("b2e").unwrap(), BITS);
+    assert_eq!(parse_hex("0xB2\nE\n").unwrap(), BITS);
+    assert!(parse_hex("b2g").unwrap_err().to_string().contains("column 3"));
+    assert_eq!(encode(&BITS, BitFormat::Hex, BitOrder::MsbFirst), b"b2e\n");
+}
+
+#[test]
+fn binary_respects_bit_order() {
+    assert_eq!(pack(&BITS, BitOrder::MsbFirst), [0xb2, 0xe0]);
+    assert_eq!(pack(&BITS, BitOrder::LsbFirst), [0x4d, 0x07]);
+    assert_eq!(unpack(&[0x4d], BitOrder::LsbFirst), BITS[..8]);
+    assert_eq!(unpack(&[0xb2], BitOrder::MsbFirst), BITS[..8]);
+}
+
+#[test]
+fn every_format_round_trips() {
+    for format in [BitFormat::Ascii, BitFormat::Binary, BitFormat::Hex] {
+        for order in [BitOrder::MsbFirst, BitOrder::LsbFirst] {
+            for length in [64, 61] {
+                let bits: Vec<u8> = (0..length).map(|i| ((i * 7 + i / 3) % 5 == 0) as u8).collect();
+                let decoded = decode(&encode(&bits, format, order), format, order).unwrap();
+                // 61 bits come back as 64 from the padded formats
+                let expected_len = if format == BitFormat::Ascii { length } else { 64 };
+                assert_eq!(decoded.len(), expected_len, "{:?} {:?}", format, order);
+                assert_eq!(truncate(decoded, length).unwrap(), bits, "{:?} {:?}", format, order);
+            }
+        }
+    }
+    assert!(truncate(vec![0; 8], 9).unwrap_err().to_string().contains("expected 9 bits, found 8"));
+}
+
+#[test]
+fn files_round_trip() {
+    let path = std::env::temp_dir().join(format!("bitstream-{}.bin", std::process::id()));
+    let path = path.to_str().unwrap();
+    write_bits(path, &BITS[..8], BitFormat::Binary, BitOrder::LsbFirst).unwrap();
+    assert_eq!(std::fs::read(path).unwrap(), [0x4d]);
+    assert_eq!(read_bits(path, BitFormat::Binary, BitOrder::LsbFirst, None).unwrap(), BITS[..8]);
+
+    write_bits(path, &BITS, BitFormat::Binary, BitOrder::MsbFirst).unwrap();
+    assert_eq!(read_bits(path, BitFormat::Binary, BitOrder::MsbFirst, None).unwrap().len(), 16);
+    assert_eq!(read_bits(path, BitFormat::Binary, BitOrder::MsbFirst, Some(12)).unwrap(), BITS);
+    std::fs::remove_file(path).unwrap();
+}
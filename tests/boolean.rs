@@ -0,0 +1,76 @@
+use symmetric_crypto::boolean::BooleanFunction;
+use symmetric_crypto::generators::{CombinerGenerator, KeystreamGenerator};
+use symmetric_crypto::geffe::{Geffe, GEFFE_CORRELATION};
+use symmetric_crypto::lfsr::LFSR;
+
+#[test]
+fn geffe_function_properties() {
+    let geffe = BooleanFunction::geffe();
+    assert_eq!(geffe.truth_table(), &[0, 0, 1, 1, 0, 1, 0, 1]);
+    assert_eq!(geffe.to_string(), "x1x3 + x2x3 + x2");
+    assert_eq!(geffe.algebraic_degree(), 2);
+    assert!(geffe.is_balanced());
+    assert_eq!(geffe.nonlinearity(), 2);
+    assert_eq!(geffe.correlation_immunity(), 0);
+    assert_eq!(geffe.input_correlations(), vec![GEFFE_CORRELATION, GEFFE_CORRELATION, 0.5]);
+    assert_eq!(geffe.vulnerable_inputs(0.05), vec![(0, 0.75), (1, 0.75)]);
+}
+
+#[test]
+fn anf_and_walsh_match_definitions() {
+    for table in [0u32, 0x96, 0xe8, 0x6ac9, 0x1234_5678] {
+        let variables = if table > 0xffff { 5 } else if table > 0xff { 4 } else { 3 };
+        let function = BooleanFunction::new(variables, (0..1 << variables).map(|x| ((table >> x) & 1) as u8).collect()).unwrap();
+
+        // f(x) is the XOR of the ANF coefficients of all monomials u contained in x
+        let anf = function.anf();
+        for x in 0..1usize << variables {
+            let value = (0..1usize << variables).filter(|&u| u & x == u).fold(0, |acc, u| acc ^ anf[u]);
+            assert_eq!(value, function.truth_table()[x]);
+        }
+
+        let spectrum = function.walsh_spectrum();
+        for (a, &w) in spectrum.iter().enumerate() {
+            let direct: i64 = (0..1usize << variables)
+                .map(|x| if (function.truth_table()[x] as u32 + (a & x).count_ones()).is_multiple_of(2) { 1 } else { -1 })
+                .sum();
+            assert_eq!(w, direct);
+        }
+    }
+}
+
+#[test]
+fn parity_and_majority() {
+    // x1 + x2 + x3 is correlation immune of order 2 but linear
+    let parity = BooleanFunction::from_fn(3, |x| x[0] ^ x[1] ^ x[2]);
+    assert_eq!(parity.correlation_immunity(), 2);
+    assert_eq!(parity.nonlinearity(), 0);
+    assert!(parity.vulnerable_inputs(0.05).is_empty());
+
+    let majority = BooleanFunction::majority(3);
+    assert_eq!(majority.to_string(), "x1x2 + x1x3 + x2x3");
+    assert_eq!(majority.input_correlations(), vec![0.75, 0.75, 0.75]);
+}
+
+#[test]
+fn combiner_with_geffe_function_matches_geffe() {
+    let registers = [((1u64 << 3) ^ 1, 25, 0x1ab_cdef), ((1 << 6) ^ (1 << 2) ^ (1 << 1) ^ 1, 26, 0x2bc_def0), ((1 << 5) ^ (1 << 2) ^ (1 << 1) ^ 1, 27, 0x3cd_ef01)];
+    let lfsrs: Vec<LFSR<u64>> = registers
+        .iter()
+        .map(|&(taps, degree, seed)| {
+            let mut lfsr = LFSR::new(taps, degree);
+            lfsr.set_state(seed);
+            lfsr
+        })
+        .collect();
+
+    let mut combiner = CombinerGenerator::new(lfsrs.clone(), BooleanFunction::geffe());
+    let mut geffe = Geffe::new(lfsrs[0].clone(), lfsrs[1].clone(), lfsrs[2].clone());
+    assert_eq!(combiner.keystream(500), geffe.keystream(500));
+}
+
+#[test]
+#[should_panic(expected = "limited to 24 variables")]
+fn from_fn_rejects_huge_truth_tables() {
+    BooleanFunction::from_fn(25, |x| x[0]);
+}
@@ -0,0 +1,30 @@
+// Sample Russian text for the tests, taken from the source corpus rather than the files the entropy
+// binary writes. Each test binary uses only some of these helpers.
+#![allow(dead_code)]
+
+use symmetric_crypto::alphabet::Alphabet;
+use symmetric_crypto::text::preprocess_text;
+use symmetric_crypto::vigenere::encode;
+
+// The corpus letters are split into a prefix to train n-gram scorers on and the rest, which the
+// sample plaintexts come from, so that no test decrypts text its scorer has seen.
+pub const TRAINING_LETTERS: usize = 300_000;
+
+// Letters of the 32-letter Russian alphabet in text_files/entropy/boloto.txt, spaces dropped.
+pub fn corpus() -> String {
+    let text = std::fs::read_to_string("text_files/entropy/boloto.txt").unwrap();
+    preprocess_text(&text, &Alphabet::russian_32()).unwrap().chars().filter(|&c| c != ' ').collect()
+}
+
+pub fn training_text() -> String {
+    corpus().chars().take(TRAINING_LETTERS).collect()
+}
+
+pub fn plaintext(length: usize) -> String {
+    corpus().chars().skip(TRAINING_LETTERS).take(length).collect()
+}
+
+// `plaintext(length)` under the Vigenère key
+pub fn ciphertext(key: &str, length: usize) -> String {
+    encode(&plaintext(length), key, &Alphabet::russian_32()).unwrap().trim_end().to_string()
+}
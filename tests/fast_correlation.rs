@@ -0,0 +1,45 @@
+use symmetric_crypto::fast_correlation::{fast_correlation_attack, parity_checks};
+use symmetric_crypto::geffe::{Geffe, GEFFE_CORRELATION};
+use symmetric_crypto::lfsr::LFSR;
+use symmetric_crypto::polynomial::FeedbackPolynomial;
+
+fn lfsr(polynomial: &str) -> LFSR<u64> {
+    LFSR::from_polynomial(&FeedbackPolynomial::parse(polynomial).unwrap())
+}
+
+#[test]
+fn parity_checks_hold_on_register_output() {
+    let mut register = lfsr("x^17 + x^3 + 1");
+    let sequence = register.generate(0x1_2345, 2000);
+    let squared = parity_checks(&[17, 3, 0], sequence.len(), false);
+    let combined = parity_checks(&[17, 3, 0], sequence.len(), true);
+
+    assert!(squared.len() > 5000);
+    assert!(squared.iter().all(|check| check.len() == 3));
+    assert!(combined.len() > 10 * squared.len());
+    assert!(combined.iter().all(|check| check.len() <= 4));
+    for check in &combined {
+        assert_eq!(check.iter().fold(0, |acc, &i| acc ^ sequence[i]), 0, "{:?}", check);
+    }
+}
+
+#[test]
+fn recovers_41_bit_register_from_geffe_keystream() {
+    let seed1 = 0x1_5a5a_c3c3u64;
+    let mut generator = Geffe::new(lfsr("x^41 + x^3 + 1"), lfsr("x^43 + x^6 + x^4 + x^3 + 1"), lfsr("x^47 + x^5 + 1"));
+    let keystream = generator.generate(seed1, 0x7_0f0f_1234, 0x5fff_0101_abcd, 20000);
+
+    let result = fast_correlation_attack(&lfsr("x^41 + x^3 + 1"), &keystream, GEFFE_CORRELATION, 20).unwrap();
+    assert_eq!(result.state, seed1);
+    assert!(result.agreement > 0.7);
+}
+
+#[test]
+fn short_keystream_uses_combined_checks() {
+    let seed1 = 0x1_5a5a_c3c3u64;
+    let mut generator = Geffe::new(lfsr("x^41 + x^3 + 1"), lfsr("x^43 + x^6 + x^4 + x^3 + 1"), lfsr("x^47 + x^5 + 1"));
+    let keystream = generator.generate(seed1, 0x7_0f0f_1234, 0x5fff_0101_abcd, 500);
+
+    let result = fast_correlation_attack(&lfsr("x^41 + x^3 + 1"), &keystream, GEFFE_CORRELATION, 20).unwrap();
+    assert_eq!(result.state, seed1);
+}
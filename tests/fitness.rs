@@ -0,0 +1,43 @@
+use symmetric_crypto::alphabet::Alphabet;
+use symmetric_crypto::fitness::{choose_best_key, NgramScorer};
+use symmetric_crypto::profile::LanguageProfile;
+
+mod common;
+
+#[test]
+fn zero_sized_ngrams_are_rejected() {
+    assert!(NgramScorer::from_corpus("абв", &Alphabet::russian_32(), 0).is_none());
+    let error = NgramScorer::from_corpus_file("text_files/entropy/boloto.txt", &Alphabet::russian_32(), 0).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+}
+
+#[test]
+fn language_outscores_ciphertext() {
+    let alphabet = Alphabet::russian_32();
+    let plaintext = common::plaintext(1000);
+    let ciphertext = common::ciphertext("ключ", 1000);
+
+    let profile = LanguageProfile::load("text_files/profiles/russian_32.txt").unwrap();
+    for scorer in [NgramScorer::from_corpus(&common::training_text(), &alphabet, 3).unwrap(), NgramScorer::from_profile(&profile)] {
+        assert!(scorer.normalized_score(&plaintext) > scorer.normalized_score(&ciphertext), "n = {}", scorer.n());
+    }
+}
+
+#[test]
+fn best_key_and_margin() {
+    let alphabet = Alphabet::russian_32();
+    let scorer = NgramScorer::from_corpus(&common::training_text(), &alphabet, 3).unwrap();
+    let plaintext = common::plaintext(1000);
+    let ciphertext = common::ciphertext("ключ", 1000);
+    let ciphertext = ciphertext.as_str();
+
+    let keys = ["клюя", "ключ", "ключ", "лмюч"].map(String::from);
+    let selection = choose_best_key(ciphertext, &keys, &alphabet, &scorer);
+    assert_eq!(selection.candidates.len(), 3);
+    assert_eq!(selection.candidates[0].key, "ключ");
+    assert_eq!(selection.candidates[0].plaintext, plaintext);
+    assert!(selection.margin.unwrap() > 0.0);
+
+    let single = choose_best_key(ciphertext, &keys[..1], &alphabet, &scorer);
+    assert_eq!(single.margin, None);
+}
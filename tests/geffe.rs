@@ -0,0 +1,171 @@
+use symmetric_crypto::boolean::BooleanFunction;
+use symmetric_crypto::generators::{CombinerGenerator, KeystreamGenerator};
+use symmetric_crypto::geffe::{find_candidates, find_candidates_parallel, AttackParameters, Geffe};
+use symmetric_crypto::geffe_attack::{attack, AttackConfig, AttackError, GeffeKey};
+use symmetric_crypto::lfsr::LFSR;
+use symmetric_crypto::polynomial::FeedbackPolynomial;
+
+#[test]
+fn parallel_search_matches_sequential_order() {
+    // x^21 + x^2 + 1, large enough to span two search chunks
+    let taps = (1u32 << 2) ^ 1;
+    let degree = 21;
+    let required_len = 64;
+
+    // flip roughly a quarter of the bits, like the Geffe combiner does
+    let noise_a = LFSR::new((1u32 << 3) ^ 1, 25).generate(0x1ab_cdef, required_len as u64);
+    let noise_b = LFSR::new((1u32 << 3) ^ 1, 25).generate(0x0fe_dcba, required_len as u64);
+    let target: Vec<u8> = LFSR::new(taps, degree)
+        .generate(0x12_3456, required_len as u64)
+        .iter()
+        .zip(noise_a.iter().zip(noise_b.iter()))
+        .map(|(&x, (&a, &b))| x ^ (a & b))
+        .collect();
+
+    // reference scan: regenerate every window from scratch, visiting states in clocking order from 1
+    let mut expected = Vec::new();
+    let mut state_register = LFSR::new(taps, degree);
+    state_register.set_state(1);
+    let mut window_register = LFSR::new(taps, degree);
+    for _ in 0..1u64 << degree {
+        let state = *state_register.state();
+        let window = window_register.generate(state, required_len as u64);
+        let discrepancy = window.iter().zip(&target).filter(|(a, b)| a != b).count();
+        if discrepancy < 24 {
+            expected.push((state, discrepancy));
+        }
+        state_register.clock();
+    }
+
+    let sequential = find_candidates(&mut LFSR::new(taps, degree), &target, required_len, 24, degree);
+    let parallel = find_candidates_parallel(&LFSR::new(taps, degree), &target, required_len, 24, degree, None);
+
+    assert!(expected.iter().any(|&(state, _)| state == 0x12_3456));
+    assert_eq!(sequential, expected);
+    assert_eq!(parallel, expected);
+}
+
+#[test]
+fn empty_window_matches_every_state() {
+    let candidates = find_candidates(&mut LFSR::new((1u32 << 2) ^ 1, 5), &[], 0, 1, 5);
+    assert_eq!(candidates.len(), 32);
+    assert!(candidates.iter().all(|&(_, discrepancy)| discrepancy == 0));
+}
+
+#[test]
+fn state_after_matches_clocking() {
+    let lfsr = LFSR::new((1u32 << 5) ^ (1 << 2) ^ (1 << 1) ^ 1, 27);
+    let sequence = LFSR::new(*lfsr.taps(), 27).generate(1, 5000);
+
+    for steps in [0u64, 1, 26, 27, 1000, 4000] {
+        let state = lfsr.state_after(&1, steps);
+        let expected = (0..27).fold(0u32, |state, i| state | (sequence[steps as usize + i] as u32) << i);
+        assert_eq!(state, expected, "after {} steps", steps);
+    }
+}
+
+#[test]
+fn attack_config_loads_beta_file() {
+    let config = AttackConfig::load("text_files/geffe/beta.toml").unwrap();
+    assert_eq!(config.degrees(), [25, 26, 27]);
+    assert_eq!(config.keystream.len(), 2048);
+    assert_eq!(config.parameters(0), AttackParameters { required_len: 222, threshold: 71 });
+
+    let overridden = AttackConfig::parse("lfsr1 = 25,3,0\nlfsr2 = 26,6,2,1,0\nlfsr3 = 27,5,2,1,0\nkeystream = k\nlfsr2_threshold = 74", |_| Ok(b"01 10".to_vec())).unwrap();
+    assert_eq!(overridden.keystream, vec![0, 1, 1, 0]);
+    assert_eq!(overridden.parameters(1), AttackParameters { required_len: 229, threshold: 74 });
+
+    let packed = AttackConfig::parse("lfsr1 = 25,3,0\nlfsr2 = 26,6,2,1,0\nlfsr3 = 27,5,2,1,0\nkeystream = k\nkeystream_format = binary\nbit_order = lsb", |_| Ok(vec![0x06])).unwrap();
+    assert_eq!(packed.keystream, vec![0, 1, 1, 0, 0, 0, 0, 0]);
+    let trimmed = AttackConfig::parse("lfsr1 = 25,3,0\nlfsr2 = 26,6,2,1,0\nlfsr3 = 27,5,2,1,0\nkeystream = k\nkeystream_format = hex\nkeystream_bits = 6", |_| Ok(b"64".to_vec())).unwrap();
+    assert_eq!(trimmed.keystream, vec![0, 1, 1, 0, 0, 1]);
+
+    assert_eq!(config.function, BooleanFunction::geffe());
+    let majority = AttackConfig::parse("lfsr1 = 25,3,0\nlfsr2 = 26,6,2,1,0\nlfsr3 = 27,5,2,1,0\nkeystream = k\nfunction = 00010111", |_| Ok(b"01".to_vec())).unwrap();
+    assert_eq!(majority.function, BooleanFunction::majority(3));
+    assert!(AttackConfig::parse("function = 0101\nkeystream = k", |_| Ok(b"01".to_vec())).is_err());
+    let error = AttackConfig::parse("lfsr1_required_len = 0\nkeystream = k", |_| Ok(b"01".to_vec())).unwrap_err();
+    assert!(error.to_string().contains("positive"), "{}", error);
+
+    let error = AttackConfig::parse("lfsr1 = x^25 + x^3 + 1\nkeystream = k", |_| Ok(b"0102".to_vec())).unwrap_err();
+    assert!(error.to_string().contains("column 4"), "{}", error);
+}
+
+fn small_combiner_config(function: BooleanFunction, seeds: [u64; 3], length: usize) -> AttackConfig {
+    let polynomials = ["x^11 + x^2 + 1", "x^12 + x^6 + x^4 + x + 1", "x^13 + x^4 + x^3 + x + 1"].map(|p| FeedbackPolynomial::parse(p).unwrap());
+    let mut lfsrs = polynomials.clone().map(|p| LFSR::<u64>::from_polynomial(&p));
+    for (lfsr, seed) in lfsrs.iter_mut().zip(seeds) {
+        lfsr.set_state(seed);
+    }
+    let keystream = CombinerGenerator::new(lfsrs.to_vec(), function.clone()).keystream(length);
+    let mut config = AttackConfig::new(polynomials, keystream);
+    config.function = function;
+    config
+}
+
+fn small_geffe_config(seeds: [u64; 3], length: usize) -> AttackConfig {
+    let polynomials = ["x^11 + x^2 + 1", "x^12 + x^6 + x^4 + x + 1", "x^13 + x^4 + x^3 + x + 1"].map(|p| FeedbackPolynomial::parse(p).unwrap());
+    let lfsrs = polynomials.clone().map(|p| LFSR::<u64>::from_polynomial(&p));
+    let [lfsr1, lfsr2, lfsr3] = lfsrs;
+    let keystream = Geffe::new(lfsr1, lfsr2, lfsr3).generate(seeds[0], seeds[1], seeds[2], length);
+    AttackConfig::new(polynomials, keystream)
+}
+
+#[test]
+fn attack_recovers_small_geffe_key() {
+    let config = small_geffe_config([0x5a3, 0x9c1, 0x1234], 600);
+    assert_eq!(config.correlated_registers(), [0, 1]);
+    let recovery = attack(&config, None).unwrap();
+    assert_eq!(recovery.keys, vec![GeffeKey { lfsr1: 0x5a3, lfsr2: 0x9c1, lfsr3: 0x1234 }]);
+    assert!(recovery.candidate_pairs.iter().any(|pair| pair.states == [0x5a3, 0x9c1]));
+}
+
+#[test]
+fn attack_reports_missing_candidates() {
+    let mut config = small_geffe_config([0x5a3, 0x9c1, 0x1234], 600);
+    config.threshold = [Some(0), None, None];
+    assert_eq!(attack(&config, None), Err(AttackError::NoCandidates { register: 1 }));
+
+    config.threshold = [None, Some(0), None];
+    assert_eq!(attack(&config, None), Err(AttackError::NoCandidates { register: 2 }));
+}
+
+#[test]
+fn attack_rejects_wide_registers() {
+    let mut config = small_geffe_config([0x5a3, 0x9c1, 0x1234], 600);
+    config.polynomials[0] = FeedbackPolynomial::parse("x^70 + x + 1").unwrap();
+    assert_eq!(attack(&config, None), Err(AttackError::RegisterTooWide { register: 1, degree: 70 }));
+}
+
+#[test]
+fn attack_rejects_short_keystream() {
+    let mut config = small_geffe_config([0x5a3, 0x9c1, 0x1234], 600);
+    config.required_len = [None, Some(5000), None];
+    assert_eq!(attack(&config, None), Err(AttackError::KeystreamTooShort { register: 2, required_len: 5000, available: 600 }));
+}
+
+#[test]
+fn attack_follows_the_combining_function() {
+    let key = GeffeKey { lfsr1: 0x5a3, lfsr2: 0x9c1, lfsr3: 0x1234 };
+
+    // LFSR1 selects, so LFSR2 and LFSR3 are searched and LFSR1 is solved for
+    let selector_first = BooleanFunction::from_fn(3, |x| if x[0] == 1 { x[1] } else { x[2] });
+    let config = small_combiner_config(selector_first, [0x5a3, 0x9c1, 0x1234], 600);
+    assert_eq!(config.correlated_registers(), [1, 2]);
+    assert_eq!(attack(&config, None).unwrap().keys, vec![key]);
+
+    // every input agrees with the majority 3/4 of the time
+    let config = small_combiner_config(BooleanFunction::majority(3), [0x5a3, 0x9c1, 0x1234], 600);
+    assert_eq!(config.correlated_registers().len(), 2);
+    assert_eq!(attack(&config, None).unwrap().keys, vec![key]);
+
+    // the complemented Geffe function agrees with LFSR1 and LFSR2 only 1/4 of the time
+    let complemented = BooleanFunction::from_fn(3, |x| 1 ^ if x[2] == 1 { x[0] } else { x[1] });
+    let config = small_combiner_config(complemented, [0x5a3, 0x9c1, 0x1234], 600);
+    assert_eq!(config.correlated_registers(), [0, 1]);
+    assert_eq!(attack(&config, None).unwrap().keys, vec![key]);
+
+    let xor = BooleanFunction::from_fn(3, |x| x[0] ^ x[1] ^ x[2]);
+    let config = small_combiner_config(xor, [0x5a3, 0x9c1, 0x1234], 600);
+    assert_eq!(attack(&config, None), Err(AttackError::UnsupportedFunction { correlated: 0 }));
+}
@@ -0,0 +1,69 @@
+use symmetric_crypto::gf2::{factor, is_irreducible, is_primitive, is_primitive_with_factors, period, Gf2Polynomial};
+use symmetric_crypto::lfsr::LFSR;
+use symmetric_crypto::polynomial::FeedbackPolynomial;
+
+fn simulated_period(taps: u32, degree: usize) -> u128 {
+    // the impulse response has the full polynomial as its minimal polynomial
+    let seed = 1u32 << (degree - 1);
+    let mut lfsr = LFSR::new(taps, degree);
+    let limit = 1u64 << degree;
+    let sequence = lfsr.generate(seed, 2 * limit + degree as u64);
+
+    (1..=limit as usize)
+        .find(|&p| (0..degree + p).all(|i| sequence[i] == sequence[i + p]))
+        .unwrap() as u128
+}
+
+#[test]
+fn period_matches_simulation_for_small_degrees() {
+    for degree in 2..=10 {
+        for taps in (1u32..(1 << degree)).step_by(2) {
+            let polynomial = FeedbackPolynomial::from_taps(&taps, degree).unwrap();
+            let f = Gf2Polynomial::from_feedback(&polynomial);
+            let expected = simulated_period(taps, degree);
+
+            assert_eq!(period(&f), Some(expected), "{}", polynomial);
+            assert_eq!(is_primitive(&f), Some(expected == (1 << degree) - 1), "{}", polynomial);
+        }
+    }
+}
+
+#[test]
+fn factorization_multiplies_back() {
+    for degree in 2..=12 {
+        for taps in (1u32..(1 << degree)).step_by(2) {
+            let f = Gf2Polynomial::from_feedback(&FeedbackPolynomial::from_taps(&taps, degree).unwrap());
+            let factors = factor(&f);
+
+            let mut product = Gf2Polynomial::one();
+            for (g, multiplicity) in &factors {
+                assert!(is_irreducible(g), "{} is not irreducible", g);
+                for _ in 0..*multiplicity {
+                    product = product.mul(g);
+                }
+            }
+            assert_eq!(product, f);
+            assert_eq!(is_irreducible(&f), factors.len() == 1 && factors[0].1 == 1, "{}", f);
+        }
+    }
+}
+
+#[test]
+fn primitivity_needs_the_complete_factorization() {
+    let polynomial = |text: &str| Gf2Polynomial::from_feedback(&FeedbackPolynomial::parse(text).unwrap());
+
+    // 2^6 - 1 = 3^2 * 7
+    let f = polynomial("x^6 + x + 1");
+    assert_eq!(is_primitive_with_factors(&f, &[3, 7]), Some(true));
+    assert_eq!(is_primitive_with_factors(&f, &[7, 3, 3]), Some(true));
+    assert_eq!(is_primitive_with_factors(&f, &[7]), None);
+    assert_eq!(is_primitive_with_factors(&f, &[3, 21]), None);
+    // x^6 + x^3 + 1 is irreducible of order 9, which only the factor 3 reveals
+    assert_eq!(is_primitive_with_factors(&polynomial("x^6 + x^3 + 1"), &[7]), None);
+    assert_eq!(is_primitive_with_factors(&polynomial("x^6 + x^3 + 1"), &[3, 7]), Some(false));
+    assert_eq!(is_primitive_with_factors(&polynomial("x^6 + 1"), &[7]), Some(false));
+
+    let factors = [3, 5, 17, 257, 641, 65537, 274177, 6700417, 67280421310721];
+    assert_eq!(is_primitive_with_factors(&polynomial("x^128 + x^7 + x^2 + x + 1"), &factors), Some(true));
+    assert_eq!(is_primitive_with_factors(&polynomial("x^129 + x^5 + 1"), &factors), None);
+}
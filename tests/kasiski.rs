@@ -0,0 +1,40 @@
+use symmetric_crypto::kasiski::{combined_key_length_ranking, find_repeated_ngrams, kasiski, rank_key_lengths, repeat_distances};
+use symmetric_crypto::profile::LanguageProfile;
+
+mod common;
+
+#[test]
+fn repeats_and_distances() {
+    let repeats = find_repeated_ngrams("abcxxabcyyabc", 3);
+    assert_eq!(repeats.len(), 1);
+    assert_eq!(repeats["abc"], [0, 5, 10]);
+    assert_eq!(repeat_distances("abcxxabcyyabc", 3, 3), [5, 5]);
+}
+
+#[test]
+fn ranking_counts_divisors() {
+    let ranking = rank_key_lengths(&[12, 18, 24, 30, 6, 7], 2, 8);
+    assert_eq!(ranking[0].length, 6);
+    assert_eq!(ranking[0].divisible, 5);
+    assert_eq!(ranking.iter().find(|s| s.length == 7).unwrap().divisible, 1);
+
+    // equal evidence for 2, 3 and 6 goes to the shortest
+    let ties: Vec<usize> = rank_key_lengths(&[6, 6], 2, 6).iter().map(|s| s.length).collect();
+    assert_eq!(ties[..3], [2, 3, 6]);
+
+    // lengths below 1 are skipped and 1 carries no evidence
+    let from_zero = rank_key_lengths(&[6, 6], 0, 3);
+    assert_eq!(from_zero.len(), 3);
+    assert!(from_zero.iter().all(|s| s.score.is_finite()));
+    assert_eq!(from_zero.iter().find(|s| s.length == 1).unwrap().score, 0.0);
+}
+
+#[test]
+fn known_ciphertext_ranks_true_length_first() {
+    let text = common::ciphertext("ключи", 3000);
+    let profile = LanguageProfile::load("text_files/profiles/russian_32.txt").unwrap();
+
+    assert_eq!(kasiski(&text, 2, 20)[0].length, 5);
+    assert_eq!(combined_key_length_ranking(&text, &profile, 2, 20)[0].length, 5);
+    assert!(combined_key_length_ranking(&text, &profile, 0, 20).iter().all(|e| e.combined_score.is_finite()));
+}
@@ -0,0 +1,76 @@
+use symmetric_crypto::bits::BitVector;
+use symmetric_crypto::lfsr::{fibonacci_to_galois_state, galois_to_fibonacci_state, GaloisLFSR, LFSR};
+use symmetric_crypto::polynomial::FeedbackPolynomial;
+
+const REGISTERS: [(u32, usize); 6] = [
+    ((1 << 3) ^ 1, 25),
+    ((1 << 6) ^ (1 << 2) ^ (1 << 1) ^ 1, 26),
+    ((1 << 5) ^ (1 << 2) ^ (1 << 1) ^ 1, 27),
+    ((1 << 6) ^ (1 << 5) ^ (1 << 1) ^ 1, 30),
+    ((1 << 3) ^ 1, 31),
+    ((1 << 7) ^ (1 << 5) ^ (1 << 3) ^ (1 << 2) ^ (1 << 1) ^ 1, 32),
+];
+
+#[test]
+fn wide_backends_match_u32_register() {
+    for &(taps, degree) in &REGISTERS {
+        for seed in [1u32, 0x0123_4567 & ((1u64 << degree) - 1) as u32, ((1u64 << degree) - 1) as u32] {
+            let expected = LFSR::new(taps, degree).generate(seed, 4096);
+
+            assert_eq!(LFSR::new(taps as u64, degree).generate(seed as u64, 4096), expected);
+            assert_eq!(LFSR::new(taps as u128, degree).generate(seed as u128, 4096), expected);
+            assert_eq!(
+                LFSR::new(BitVector::from_u64(taps as u64, degree), degree).generate(BitVector::from_u64(seed as u64, degree), 4096),
+                expected
+            );
+        }
+    }
+}
+
+#[test]
+fn bit_vector_register_matches_u128_above_64_bits() {
+    // x^89 + x^38 + 1
+    let degree = 89;
+    let taps = 1u128 << 38 | 1;
+    let seed = 0x1_2345_6789_abcd_ef01_2345u128;
+
+    let mut taps_bits = BitVector::new(degree);
+    let mut seed_bits = BitVector::new(degree);
+    for i in 0..degree {
+        taps_bits.set(i, (taps >> i & 1) as u8);
+        seed_bits.set(i, (seed >> i & 1) as u8);
+    }
+
+    assert_eq!(
+        LFSR::new(taps_bits, degree).generate(seed_bits, 2048),
+        LFSR::new(taps, degree).generate(seed, 2048)
+    );
+}
+
+#[test]
+fn feedback_polynomial_formats_agree() {
+    let from_exponents = FeedbackPolynomial::from_exponents(&[25, 3, 0]).unwrap();
+    assert_eq!(FeedbackPolynomial::parse("x^25 + x^3 + 1"), Some(from_exponents.clone()));
+    assert_eq!(FeedbackPolynomial::parse("25, 3, 0"), Some(from_exponents.clone()));
+    assert_eq!(FeedbackPolynomial::parse("0x2000009"), Some(from_exponents.clone()));
+    assert_eq!(FeedbackPolynomial::from_taps(&((1u32 << 3) ^ 1), 25), Some(from_exponents.clone()));
+    assert_eq!(from_exponents.to_hex(), "0x2000009");
+    assert_eq!(from_exponents.to_string(), "x^25 + x^3 + 1");
+    assert_eq!(from_exponents.fibonacci_taps::<u32>(), (1 << 3) ^ 1);
+    assert_eq!(FeedbackPolynomial::parse("x^25 + x^3"), None);
+}
+
+#[test]
+fn galois_and_fibonacci_forms_generate_the_same_sequence() {
+    for &(taps, degree) in &REGISTERS {
+        let polynomial = FeedbackPolynomial::from_taps(&taps, degree).unwrap();
+        let mut fibonacci = LFSR::<u64>::from_polynomial(&polynomial);
+        let mut galois = GaloisLFSR::<u64>::from_polynomial(&polynomial);
+
+        for seed in [1u64, 0x0155_aa33 & ((1 << degree) - 1), (1 << degree) - 1] {
+            let galois_seed = fibonacci_to_galois_state(&seed, &polynomial);
+            assert_eq!(galois_to_fibonacci_state(&galois_seed, &polynomial), seed);
+            assert_eq!(galois.generate(galois_seed, 4096), fibonacci.generate(seed, 4096));
+        }
+    }
+}
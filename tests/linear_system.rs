@@ -0,0 +1,120 @@
+use symmetric_crypto::bits::BitVector;
+use symmetric_crypto::geffe::{find_lfsr3_states, recover_lfsr3_states, solve_lfsr3_states};
+use symmetric_crypto::lfsr::LFSR;
+use symmetric_crypto::linear_system::{LinearSystem, Solution};
+
+fn brute_force(system: &[(u64, u8)], variables: usize) -> Vec<u64> {
+    (0..1u64 << variables)
+        .filter(|x| system.iter().all(|&(row, rhs)| ((row & x).count_ones() % 2) as u8 == rhs))
+        .collect()
+}
+
+#[test]
+fn solutions_match_brute_force() {
+    let variables = 6;
+    let mut seed = 0x2545_f491_4f6c_dd1du64;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+
+    for equations in 0..10 {
+        for _ in 0..20 {
+            let rows: Vec<(u64, u8)> = (0..equations).map(|_| (next() & 0x3f, (next() & 1) as u8)).collect();
+            let mut system = LinearSystem::new(variables);
+            for &(row, rhs) in &rows {
+                system.add_equation(BitVector::from_u64(row, variables), rhs);
+            }
+
+            let mut solutions: Vec<u64> = system
+                .solve()
+                .enumerate(variables)
+                .unwrap()
+                .iter()
+                .map(|x| x.words()[0])
+                .collect();
+            solutions.sort_unstable();
+            assert_eq!(solutions, brute_force(&rows, variables), "{:?}", rows);
+        }
+    }
+}
+
+#[test]
+fn unique_and_inconsistent_systems() {
+    let mut system = LinearSystem::new(3);
+    system.add_equation(BitVector::from_u64(0b011, 3), 1);
+    system.add_equation(BitVector::from_u64(0b110, 3), 0);
+    system.add_equation(BitVector::from_u64(0b100, 3), 1);
+    assert_eq!(system.solve(), Solution::Unique(BitVector::from_u64(0b110, 3)));
+    assert_eq!(system.rank(), 3);
+
+    system.add_equation(BitVector::from_u64(0b101, 3), 0);
+    assert_eq!(system.solve(), Solution::Inconsistent);
+}
+
+#[test]
+fn output_equations_reproduce_generate() {
+    let mut lfsr = LFSR::new((1u64 << 5) ^ (1 << 2) ^ (1 << 1) ^ 1, 27);
+    let seed = 0x3a5_c0ffu64;
+    let sequence = lfsr.generate(seed, 300);
+    let state = BitVector::from_u64(seed, 27);
+
+    for (t, equation) in lfsr.output_equations(300).iter().enumerate() {
+        assert_eq!(equation.and_parity(&state), sequence[t], "bit {}", t);
+    }
+}
+
+#[test]
+fn algebraic_lfsr3_recovery_matches_enumeration() {
+    let mut lfsr1 = LFSR::new((1u64 << 2) ^ 1, 11);
+    let mut lfsr2 = LFSR::new((1u64 << 6) ^ (1 << 4) ^ (1 << 1) ^ 1, 12);
+    let mut lfsr3 = LFSR::new((1u64 << 4) ^ (1 << 3) ^ (1 << 1) ^ 1, 13);
+
+    for length in [20, 40, 200] {
+        let x1 = lfsr1.generate(0x5a3, length);
+        let x2 = lfsr2.generate(0x9c1, length);
+        let x3 = lfsr3.generate(0x1234, length);
+        let z: Vec<u8> = (0..length as usize).map(|i| if x3[i] == 1 { x1[i] } else { x2[i] }).collect();
+
+        let mut solved = solve_lfsr3_states(&lfsr3, &x1, &x2, &z).unwrap();
+        let mut enumerated = find_lfsr3_states(&lfsr3, &x1, &x2, &z, 13).unwrap();
+        solved.sort_unstable();
+        enumerated.sort_unstable();
+        assert_eq!(solved, enumerated, "length {}", length);
+        assert!(solved.contains(&0x1234));
+    }
+}
+
+#[test]
+fn enumeration_is_capped_by_free_bits() {
+    // x1 = x2 everywhere reveals nothing about LFSR3, so all 13 of its bits are free
+    let lfsr3 = LFSR::new((1u64 << 4) ^ (1 << 3) ^ (1 << 1) ^ 1, 13);
+    let x = vec![1, 0, 0, 1, 1, 0, 1, 0, 1, 1, 1, 0, 0, 0, 1, 0];
+    assert_eq!(find_lfsr3_states(&lfsr3, &x, &x, &x, 12), None);
+    assert_eq!(find_lfsr3_states(&lfsr3, &x, &x, &x, 13).unwrap().len(), 1 << 13);
+
+    let wide = LFSR::new((1u64 << 3) ^ 1, 20);
+    assert_eq!(solve_lfsr3_states(&wide, &x, &x, &x), Err::<Vec<u64>, _>(20));
+}
+
+#[test]
+fn recovery_falls_back_to_enumeration() {
+    // x1 != x2 only at the first three positions: 17 of the 20 state bits stay free, too many to list
+    // from the system but few enough to enumerate
+    let wide = LFSR::new((1u64 << 3) ^ 1, 20);
+    let x = vec![1, 0, 0, 1, 1, 0, 1, 0, 1, 1, 1, 0, 0, 0, 1, 0];
+    let mut flipped = x.clone();
+    for bit in &mut flipped[..3] {
+        *bit ^= 1;
+    }
+
+    assert_eq!(solve_lfsr3_states(&wide, &x, &flipped, &x), Err::<Vec<u64>, _>(17));
+    let states = recover_lfsr3_states(&wide, &x, &flipped, &x).unwrap();
+    assert_eq!(states.len(), 1 << 17);
+    assert!(states.iter().all(|&state| state & 0b111 == 0b111));
+
+    let wider = LFSR::new((1u64 << 3) ^ 1, 30);
+    assert_eq!(recover_lfsr3_states(&wider, &x, &x, &x), Err::<Vec<u64>, _>(30));
+}
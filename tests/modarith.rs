@@ -0,0 +1,68 @@
+use symmetric_crypto::modarith::{extended_gcd, gcd, inverse, matrix_inverse, matrix_mul, solve_congruence};
+
+const MAX_MODULUS: i64 = 40;
+
+#[test]
+fn extended_gcd_satisfies_bezout() {
+    for a in -MAX_MODULUS..=MAX_MODULUS {
+        for b in -MAX_MODULUS..=MAX_MODULUS {
+            let (g, x, y) = extended_gcd(a, b);
+            let expected = (1..=a.abs().max(b.abs())).rev().find(|d| a % d == 0 && b % d == 0).unwrap_or(0);
+            assert_eq!(g, expected, "gcd({}, {})", a, b);
+            assert_eq!(a * x + b * y, g, "bezout({}, {})", a, b);
+        }
+    }
+}
+
+#[test]
+fn inverse_matches_brute_force() {
+    for n in 2..=MAX_MODULUS {
+        for a in -n..2 * n {
+            let expected = (0..n).find(|x| (a * x).rem_euclid(n) == 1);
+            assert_eq!(inverse(a, n), expected, "inverse of {} mod {}", a, n);
+        }
+    }
+}
+
+#[test]
+fn solve_congruence_matches_brute_force() {
+    for n in 1..=MAX_MODULUS {
+        for a in -n..2 * n {
+            for b in -n..2 * n {
+                let expected: Vec<i64> = (0..n).filter(|x| (a * x - b).rem_euclid(n) == 0).collect();
+                assert_eq!(solve_congruence(a, b, n), expected, "{}x = {} mod {}", a, b, n);
+            }
+        }
+    }
+}
+
+#[test]
+fn solve_congruence_returns_gcd_many_roots() {
+    assert_eq!(solve_congruence(6, 9, 15), vec![4, 9, 14]);
+    assert_eq!(solve_congruence(6, 10, 15), Vec::<i64>::new());
+    assert_eq!(gcd(6, 15), 3);
+}
+
+#[test]
+fn matrix_inverse_matches_brute_force() {
+    let identity = [[1, 0], [0, 1]];
+
+    for n in 2..=6i64 {
+        for value in 0..n.pow(4) {
+            let m = [[value % n, value / n % n], [value / n.pow(2) % n, value / n.pow(3)]];
+            let invertible = (0..n.pow(4)).any(|other| {
+                let candidate = [[other % n, other / n % n], [other / n.pow(2) % n, other / n.pow(3)]];
+                matrix_mul(&m, &candidate, n) == identity
+            });
+
+            match matrix_inverse(&m, n) {
+                Some(inverse) => {
+                    assert!(invertible);
+                    assert_eq!(matrix_mul(&m, &inverse, n), identity);
+                    assert_eq!(matrix_mul(&inverse, &m, n), identity);
+                }
+                None => assert!(!invertible, "{:?} mod {} should be invertible", m, n),
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use symmetric_crypto::alphabet::Alphabet;
+use symmetric_crypto::profile::LanguageProfile;
+
+const PROFILE: &str = "# a toy language
+alphabet abcd
+unigram a 0.4
+unigram b 0.3
+unigram c 0.2
+unigram d 0.1
+
+bigram ab 0.5
+bigram ba 0.25
+bigram cd 0.25
+trigram aba 1
+";
+
+fn parse_error(contents: &str) -> String {
+    LanguageProfile::parse(contents).unwrap_err().to_string()
+}
+
+#[test]
+fn parse_and_save_round_trip() {
+    let profile = LanguageProfile::parse(PROFILE).unwrap();
+    assert_eq!(profile.alphabet(), &Alphabet::new("abcd").unwrap());
+    assert_eq!(profile.unigrams(), [0.4, 0.3, 0.2, 0.1]);
+    assert_eq!(profile.unigram('c'), 0.2);
+    assert_eq!(profile.bigram("ba"), 0.25);
+    assert_eq!(profile.bigram("dd"), 0.0);
+    assert_eq!(profile.trigrams().unwrap()["aba"], 1.0);
+
+    let path = std::env::temp_dir().join(format!("profile-{}.txt", std::process::id()));
+    let path = path.to_str().unwrap();
+    profile.save(path).unwrap();
+    let loaded = LanguageProfile::load(path).unwrap();
+    std::fs::remove_file(path).unwrap();
+
+    assert_eq!(loaded.alphabet(), profile.alphabet());
+    assert_eq!(loaded.unigrams(), profile.unigrams());
+    assert_eq!(loaded.bigrams(), profile.bigrams());
+    assert_eq!(loaded.trigrams(), profile.trigrams());
+}
+
+#[test]
+fn malformed_lines_are_reported_with_their_number() {
+    assert!(parse_error("alphabet ab\nunigram a 0.5\nunigram b half").contains("line 3: invalid probability"));
+    assert!(parse_error("alphabet ab\nunigram a 1.5\nunigram b 0.5").contains("line 2: probability out of range"));
+    assert!(parse_error("unigram a 0.5\nalphabet ab").contains("line 1: n-gram before 'alphabet' line"));
+    assert!(parse_error("alphabet ab\nunigram a 0.5\nunigram b 0.5\nquadgram abab 1").contains("line 4: unknown n-gram kind"));
+    assert!(parse_error("alphabet ab\nunigram a 0.5\nbigram aba 0.5").contains("line 3: n-gram length"));
+    assert!(parse_error("alphabet ab\nunigram a 0.5\nbigram az 0.5").contains("line 3: n-gram contains letters outside"));
+    assert!(parse_error("alphabet ab\nunigram a 0.5").contains("missing unigram for 'b'"));
+    assert!(parse_error("alphabet ab\nunigram a 0.5\nunigram b 0.3").contains("sum to"));
+}
+
+#[test]
+fn alphabet_mismatch_is_rejected() {
+    let profile = LanguageProfile::parse(PROFILE).unwrap();
+    assert!(profile.check_alphabet(&Alphabet::new("abcd").unwrap()).is_ok());
+    let error = profile.check_alphabet(&Alphabet::english_26()).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+
+    let russian = LanguageProfile::load("text_files/profiles/russian_32.txt").unwrap();
+    assert!(russian.check_alphabet(&Alphabet::russian_32()).is_ok());
+    assert!(russian.check_alphabet(&Alphabet::russian_33()).is_err());
+}
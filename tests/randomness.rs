@@ -0,0 +1,86 @@
+// Reference p-values are the worked examples of NIST SP 800-22 rev. 1a, section 2.
+use symmetric_crypto::geffe::Geffe;
+use symmetric_crypto::lfsr::LFSR;
+use symmetric_crypto::randomness::{
+    approximate_entropy, block_frequency, cumulative_sums, linear_complexity, longest_run, monobit, run_all, runs, serial,
+    spectral, SIGNIFICANCE,
+};
+use symmetric_crypto::statistics::igamc;
+
+fn bits(text: &str) -> Vec<u8> {
+    text.bytes().map(|b| b - b'0').collect()
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-5, "{} != {}", actual, expected);
+}
+
+const LONGEST_RUN_EXAMPLE: &str = "11001100000101010110110001001100111000000000001001001101010100010001001111010110100000001101011111001100111001101101100010110010";
+
+#[test]
+fn incomplete_gamma() {
+    // Q(1, x) = e^-x and Q(a, x) + P(a, x) = 1 at the series/continued fraction switch
+    assert_close(igamc(1.0, 2.0), (-2.0f64).exp());
+    assert_close(igamc(3.0, 4.0), (-4.0f64).exp() * (1.0 + 4.0 + 8.0));
+    assert_close(igamc(0.5, 1.0), 0.157299);
+}
+
+#[test]
+fn nist_examples() {
+    assert_close(monobit(&bits("1011010101")).unwrap(), 0.527089);
+    assert_close(block_frequency(&bits("0110011010"), 3).unwrap(), 0.801252);
+    assert_close(runs(&bits("1001101011")).unwrap(), 0.147232);
+    // chi^2 = 4.882605 as in the example; its p-value is printed as 0.180609, Q(1.5, chi^2 / 2) = 0.180598
+    let p = longest_run(&bits(LONGEST_RUN_EXAMPLE)).unwrap();
+    assert!((p - 0.180609).abs() < 2e-5, "{} != 0.180609", p);
+
+    let (p1, p2) = serial(&bits("0011011101"), 3).unwrap();
+    assert_close(p1, 0.808792);
+    assert_close(p2, 0.670320);
+
+    assert_close(approximate_entropy(&bits("0100110101"), 3).unwrap(), 0.261961);
+    assert_close(cumulative_sums(&bits("1011010111")).unwrap().0, 0.4116588);
+    // all five moduli (0, 2, 4.47, 2, 4.47) lie below T = 5.47, so N1 = 5 rather than the example's 4
+    // (which would give p = 0.029523)
+    assert_close(spectral(&bits("1001010011")).unwrap(), 0.468160);
+}
+
+#[test]
+fn serial_survives_rounding_below_zero() {
+    // psi^2 differences of this sequence round to slightly negative values
+    let sequence = bits("1101011000110110111011000010011000000110100111010101111011101001110000011011000010111101000110010011");
+    for m in 2..=5 {
+        let (p1, p2) = serial(&sequence, m).unwrap();
+        assert!((0.0..=1.0).contains(&p1) && (0.0..=1.0).contains(&p2), "m = {}: {} {}", m, p1, p2);
+    }
+    assert!(!run_all(&sequence).is_empty());
+}
+
+#[test]
+fn spectral_handles_power_of_two_and_other_lengths() {
+    // the same pattern repeated must not depend on whether Bluestein's method is used
+    let periodic: Vec<u8> = (0..4096).map(|i| (i % 2) as u8).collect();
+    assert!(spectral(&periodic).unwrap() < SIGNIFICANCE);
+    assert!(spectral(&periodic[..4095]).unwrap() < SIGNIFICANCE);
+}
+
+#[test]
+fn lfsr_fails_linear_complexity_and_geffe_passes_the_suite() {
+    let mut short = LFSR::new((1u64 << 3) ^ 1, 25);
+    let lfsr_bits = short.generate(0x1ab_cdef, 20_000);
+    assert!(linear_complexity(&lfsr_bits, 500).unwrap() < SIGNIFICANCE);
+
+    let mut generator = Geffe::new(
+        LFSR::new((1u64 << 3) ^ 1, 25),
+        LFSR::new((1 << 6) ^ (1 << 2) ^ (1 << 1) ^ 1, 26),
+        LFSR::new((1 << 5) ^ (1 << 2) ^ (1 << 1) ^ 1, 27),
+    );
+    let keystream = generator.generate(0x1ab_cdef, 0x2bc_def0, 0x3cd_ef01, 20_000);
+    let results = run_all(&keystream);
+
+    assert_eq!(results.len(), 9);
+    for result in &results {
+        // a single seed is one sample, so allow a generous margin over the significance level
+        assert!(result.p_values.iter().all(|&p| p > 1e-4), "{:?}", result);
+    }
+}
@@ -0,0 +1,27 @@
+use symmetric_crypto::alphabet::Alphabet;
+use symmetric_crypto::fitness::NgramScorer;
+use symmetric_crypto::refine::refine_key;
+
+mod common;
+
+#[test]
+fn refinement_repairs_corrupted_key() {
+    let alphabet = Alphabet::russian_32();
+    let scorer = NgramScorer::from_corpus(&common::training_text(), &alphabet, 3).unwrap();
+    let ciphertext = common::ciphertext("делолисобак", 2000);
+
+    let refinement = refine_key(&ciphertext, "делалисобаг", &alphabet, &scorer, 2000, 7);
+    assert_eq!(refinement.key, "делолисобак");
+    assert!(!refinement.improvements.is_empty());
+    assert!(refinement.improvements.windows(2).all(|pair| pair[0].score < pair[1].score));
+}
+
+#[test]
+fn empty_key_is_returned_unchanged() {
+    let alphabet = Alphabet::russian_32();
+    let scorer = NgramScorer::from_corpus("абвгдабвгд", &alphabet, 2).unwrap();
+    let refinement = refine_key("абвгд", "", &alphabet, &scorer, 100, 1);
+    assert_eq!(refinement.key, "");
+    assert!(refinement.improvements.is_empty());
+    assert_eq!(refinement.score, scorer.normalized_score("абвгд"));
+}
@@ -0,0 +1,22 @@
+use symmetric_crypto::geffe::{attack_parameters, AttackParameters, GEFFE_CORRELATION};
+use symmetric_crypto::statistics::{normal_cdf, normal_quantile};
+
+#[test]
+fn quantile_inverts_cdf() {
+    for &p in &[1e-10, 1e-6, 0.01, 0.25, 0.5, 0.9, 0.99] {
+        let x = normal_quantile(p);
+        assert!((normal_cdf(x) - p).abs() / p < 1e-6, "p = {}", p);
+    }
+    assert!((normal_quantile(0.99) - 2.3263).abs() < 1e-3);
+}
+
+// N* agrees with reports/geffe for all four registers. The report works with t = 2.33 and 6 and
+// gives C = 71, 73, 80.4 and 83.6, so its thresholds (C rounded up; the old constants used 74 for
+// degree 26) can be one off from the exact quantiles used here.
+#[test]
+fn parameters_from_normal_approximation() {
+    let expected = [(25, 222, 71), (26, 229, 73), (30, 258, 81), (31, 265, 83)];
+    for (degree, required_len, threshold) in expected {
+        assert_eq!(attack_parameters(degree, GEFFE_CORRELATION, 0.01, None), AttackParameters { required_len, threshold }, "degree {}", degree);
+    }
+}
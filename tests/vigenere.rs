@@ -0,0 +1,27 @@
+use symmetric_crypto::profile::LanguageProfile;
+use symmetric_crypto::vigenere::{compute_r, select_key_length};
+
+mod common;
+
+#[test]
+fn candidates_cover_the_range_sorted_by_deviation() {
+    let profile = LanguageProfile::load("text_files/profiles/russian_32.txt").unwrap();
+    let candidates = compute_r(&common::ciphertext("ключ", 2000), &profile, 3, 12);
+
+    let mut lengths: Vec<usize> = candidates.iter().map(|c| c.length).collect();
+    lengths.sort_unstable();
+    assert_eq!(lengths, (3..=12).collect::<Vec<_>>());
+    assert!(candidates.windows(2).all(|pair| pair[0].deviation <= pair[1].deviation));
+}
+
+#[test]
+fn true_period_is_preferred_over_its_multiples() {
+    let profile = LanguageProfile::load("text_files/profiles/russian_32.txt").unwrap();
+    for key in ["кот", "ключ", "рыба"] {
+        let candidates = compute_r(&common::ciphertext(key, 3000), &profile, 2, 20);
+        let period = key.chars().count();
+        // on this text a multiple of the period lies closest to the language index
+        assert!(candidates[0].length > period && candidates[0].length.is_multiple_of(period), "{}", key);
+        assert_eq!(select_key_length(&candidates, &profile), Some(period), "{}", key);
+    }
+}